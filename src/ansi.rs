@@ -0,0 +1,170 @@
+use ratatui::{
+    buffer::{Buffer, Cell},
+    style::{Color, Modifier, Style},
+    text::Line,
+};
+use unicode_width::UnicodeWidthStr;
+
+/// Convert every row of a rendered buffer into a string with ANSI escape codes
+pub fn buffer_to_lines(buf: &Buffer) -> Vec<String> {
+    let area = buf.area;
+    let plain = Cell::default().style();
+    let mut lines = Vec::with_capacity(area.height as usize);
+
+    for y in area.top()..area.bottom() {
+        let mut line = String::new();
+        let mut current = plain;
+        let mut skip = 0;
+
+        for x in area.left()..area.right() {
+            // Wide characters cover the cells that follow them
+            if skip > 0 {
+                skip -= 1;
+                continue;
+            }
+
+            let cell = &buf[(x, y)];
+            let style = cell.style();
+            if style != current {
+                line.push_str(&sgr(style));
+                current = style;
+            }
+
+            let symbol = cell.symbol();
+            line.push_str(symbol);
+            skip = symbol.width().saturating_sub(1);
+        }
+
+        if current != plain {
+            line.push_str("\x1b[0m");
+        }
+
+        lines.push(line);
+    }
+
+    lines
+}
+
+/// Build the SGR sequence that switches the terminal to `style`
+pub fn sgr(style: Style) -> String {
+    let mut codes = vec!["0".to_string()];
+
+    let modifiers = [
+        (Modifier::BOLD, "1"),
+        (Modifier::DIM, "2"),
+        (Modifier::ITALIC, "3"),
+        (Modifier::UNDERLINED, "4"),
+        (Modifier::SLOW_BLINK, "5"),
+        (Modifier::RAPID_BLINK, "6"),
+        (Modifier::REVERSED, "7"),
+        (Modifier::HIDDEN, "8"),
+        (Modifier::CROSSED_OUT, "9"),
+    ];
+    for (modifier, code) in modifiers {
+        if style.add_modifier.contains(modifier) {
+            codes.push(code.to_string());
+        }
+    }
+
+    if let Some(fg) = style.fg.and_then(|c| color_code(c, false)) {
+        codes.push(fg);
+    }
+    if let Some(bg) = style.bg.and_then(|c| color_code(c, true)) {
+        codes.push(bg);
+    }
+
+    format!("\x1b[{}m", codes.join(";"))
+}
+
+fn color_code(color: Color, background: bool) -> Option<String> {
+    let offset = if background { 10 } else { 0 };
+    let base = match color {
+        Color::Reset => return None,
+        Color::Black => 30,
+        Color::Red => 31,
+        Color::Green => 32,
+        Color::Yellow => 33,
+        Color::Blue => 34,
+        Color::Magenta => 35,
+        Color::Cyan => 36,
+        Color::Gray => 37,
+        Color::DarkGray => 90,
+        Color::LightRed => 91,
+        Color::LightGreen => 92,
+        Color::LightYellow => 93,
+        Color::LightBlue => 94,
+        Color::LightMagenta => 95,
+        Color::LightCyan => 96,
+        Color::White => 97,
+        Color::Indexed(i) => return Some(format!("{};5;{}", 38 + offset, i)),
+        Color::Rgb(r, g, b) => return Some(format!("{};2;{};{};{}", 38 + offset, r, g, b)),
+    };
+
+    Some((base + offset).to_string())
+}
+
+/// Remove ANSI escape sequences from `s`
+pub fn strip(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut chars = s.chars().peekable();
+
+    while let Some(ch) = chars.next() {
+        if ch != '\x1b' {
+            out.push(ch);
+            continue;
+        }
+
+        match chars.next() {
+            // CSI: parameters up to a final byte in '@'..='~'
+            Some('[') => {
+                for c in chars.by_ref() {
+                    if ('@'..='~').contains(&c) {
+                        break;
+                    }
+                }
+            }
+            // OSC (e.g. hyperlinks): terminated by BEL or ESC '\'
+            Some(']') => {
+                while let Some(c) = chars.next() {
+                    if c == '\x07' || (c == '\x1b' && chars.next_if_eq(&'\\').is_some()) {
+                        break;
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    out
+}
+
+/// Display width of `s` in terminal columns, ignoring ANSI escape sequences
+pub fn width(s: &str) -> usize {
+    strip(s).width()
+}
+
+/// Convert a styled line into a string with ANSI escape codes
+pub fn line_to_string(line: &Line) -> String {
+    let mut out = String::new();
+    let mut styled = false;
+
+    for span in &line.spans {
+        let style = line.style.patch(span.style);
+        if style == Style::default() {
+            if styled {
+                out.push_str("\x1b[0m");
+                styled = false;
+            }
+        } else {
+            out.push_str(&sgr(style));
+            styled = true;
+        }
+        out.push_str(&span.content);
+    }
+
+    if styled {
+        out.push_str("\x1b[0m");
+    }
+
+    out
+}
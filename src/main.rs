@@ -2,13 +2,17 @@
 #![allow(unused_imports)]
 #![allow(unused_variables)]
 
+mod ansi;
 mod error;
 mod style;
 mod tui;
 mod widgets;
 
 use clap::{Parser, Subcommand};
-use widgets::{block, confirm, gauge, input, table};
+use widgets::{
+    block, choose, confirm, file, filter, format, gauge, input, join, log, pager, spin, table,
+    write,
+};
 
 #[derive(Parser)]
 #[command(name = "nibble")]
@@ -25,11 +29,31 @@ enum Commands {
     /// Render a gauge/progress bar
     Gauge(gauge::GaugeArgs),
     /// Render a table
-    Table(table::TableArgs),
+    Table(Box<table::TableArgs>),
     /// Render User Input
     Input(input::InputArgs),
     /// Render confirmation buttons
     Confirm(confirm::ConfirmArgs),
+    /// Choose one or more options from a list
+    Choose(choose::ChooseArgs),
+    /// Fuzzy-filter a list of options
+    Filter(filter::FilterArgs),
+    /// Display a spinner while running a command
+    Spin(spin::SpinArgs),
+    /// Render multi-line text input
+    Write(write::WriteArgs),
+    /// Scroll through long text
+    Pager(pager::PagerArgs),
+    /// Pick a file or directory from the filesystem
+    File(file::FileArgs),
+    /// Print styled text in a box without waiting for input
+    Style(widgets::style::StyleArgs),
+    /// Join text blocks horizontally or vertically
+    Join(join::JoinArgs),
+    /// Format Markdown, code, templates or emoji for the terminal
+    Format(format::FormatArgs),
+    /// Print a structured, levelled log line
+    Log(log::LogArgs),
 }
 
 fn main() -> anyhow::Result<()> {
@@ -38,9 +62,19 @@ fn main() -> anyhow::Result<()> {
     match cli.command {
         Commands::Block(args) => block::run(args)?,
         Commands::Gauge(args) => gauge::run(args)?,
-        Commands::Table(args) => table::run(args)?,
+        Commands::Table(args) => table::run(*args)?,
         Commands::Input(args) => input::run(args)?,
         Commands::Confirm(args) => confirm::run(args)?,
+        Commands::Choose(args) => choose::run(args)?,
+        Commands::Filter(args) => filter::run(args)?,
+        Commands::Spin(args) => spin::run(args)?,
+        Commands::Write(args) => write::run(args)?,
+        Commands::Pager(args) => pager::run(args)?,
+        Commands::File(args) => file::run(args)?,
+        Commands::Style(args) => widgets::style::run(args)?,
+        Commands::Join(args) => join::run(args)?,
+        Commands::Format(args) => format::run(args)?,
+        Commands::Log(args) => log::run(args)?,
     }
 
     Ok(())
@@ -61,6 +61,11 @@ impl StyleConfig {
         Ok(style)
     }
 
+    /// Style for the item under the cursor in selectable widgets
+    pub fn highlight_style(&self) -> Result<RatatuiStyle> {
+        Ok(self.text_style()?.add_modifier(Modifier::REVERSED))
+    }
+
     pub fn gauge_style(&self) -> Result<RatatuiStyle> {
         let mut style = RatatuiStyle::default();
 
@@ -107,8 +112,19 @@ pub fn parse_color(color: &str) -> Result<Color> {
         "light_yellow" => Ok(Color::LightYellow),
         "light_cyan" => Ok(Color::LightCyan),
         "light_magenta" => Ok(Color::LightMagenta),
+        hex if hex.starts_with('#') => {
+            let digits = &hex[1..];
+            if digits.len() != 6 || !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+                return Err(NibbleError::InvalidColor(format!(
+                    "Invalid hex color '{}'. Expected #rrggbb",
+                    color
+                )));
+            }
+            let rgb = u32::from_str_radix(digits, 16).unwrap_or_default();
+            Ok(Color::Rgb((rgb >> 16) as u8, (rgb >> 8) as u8, rgb as u8))
+        }
         _ => Err(NibbleError::InvalidColor(format!(
-            "Unknown color '{}'. Valid colors: red, green, blue, yellow, cyan, magenta, white, black, gray, dark_gray, light_*",
+            "Unknown color '{}'. Valid colors: red, green, blue, yellow, cyan, magenta, white, black, gray, dark_gray, light_*, #rrggbb",
             color
         ))),
     }
@@ -1,12 +1,36 @@
 use crate::error::{NibbleError, Result};
+use clap::Args;
 use crossterm::{
     ExecutableCommand, cursor,
     terminal::{EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode},
 };
 use ratatui::{Terminal, TerminalOptions, Viewport, backend::CrosstermBackend};
-use std::io::{Stdout, stderr, stdout};
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, IsTerminal, Read, Write, stderr, stdin, stdout};
+#[cfg(unix)]
+use std::sync::atomic::{AtomicI32, Ordering};
+use std::sync::mpsc::Sender;
 
-pub type Tui = Terminal<CrosstermBackend<Stdout>>;
+pub type Tui = Terminal<CrosstermBackend<Output>>;
+
+/// Where the TUI is drawn: the terminal itself when stdout is redirected, else stdout
+pub struct Output(Option<File>);
+
+impl Write for Output {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        match self.0 {
+            Some(ref mut tty) => tty.write(buf),
+            None => stdout().write(buf),
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match self.0 {
+            Some(ref mut tty) => tty.flush(),
+            None => stdout().flush(),
+        }
+    }
+}
 
 /// Initialize terminal with inline viewport
 pub fn init_inline(height: u16) -> Result<Tui> {
@@ -16,6 +40,8 @@ pub fn init_inline(height: u16) -> Result<Tui> {
         ));
     }
 
+    let output = Output(draw_on_tty());
+
     enable_raw_mode()
         .map_err(|e| NibbleError::TerminalInit(format!("Failed to enable raw mode: {}", e)))?;
 
@@ -24,7 +50,7 @@ pub fn init_inline(height: u16) -> Result<Tui> {
         .execute(cursor::Hide)
         .map_err(|e| NibbleError::TerminalInit(format!("Failed to hide cursor: {}", e)))?;
 
-    let backend = CrosstermBackend::new(stdout());
+    let backend = CrosstermBackend::new(output);
     let terminal = Terminal::with_options(
         backend,
         TerminalOptions {
@@ -38,6 +64,8 @@ pub fn init_inline(height: u16) -> Result<Tui> {
 
 /// Initialize terminal with fullscreen (alternate screen)
 pub fn init_fullscreen() -> Result<Tui> {
+    let output = Output(draw_on_tty());
+
     enable_raw_mode()
         .map_err(|e| NibbleError::TerminalInit(format!("Failed to enable raw mode: {}", e)))?;
 
@@ -49,7 +77,7 @@ pub fn init_fullscreen() -> Result<Tui> {
         .execute(cursor::Hide)
         .map_err(|e| NibbleError::TerminalInit(format!("Failed to hide cursor: {}", e)))?;
 
-    let backend = CrosstermBackend::new(stdout());
+    let backend = CrosstermBackend::new(output);
     let terminal = Terminal::new(backend)
         .map_err(|e| NibbleError::TerminalInit(format!("Failed to create terminal: {}", e)))?;
 
@@ -64,5 +92,151 @@ pub fn restore() -> Result<()> {
     stderr()
         .execute(cursor::Show)
         .map_err(|e| NibbleError::TerminalInit(format!("Failed to show cursor: {}", e)))?;
+    restore_stdout();
     Ok(())
 }
+
+/// Descriptor holding the real stdout while stdout points at the terminal
+#[cfg(unix)]
+static SAVED_STDOUT: AtomicI32 = AtomicI32::new(-1);
+
+/// When stdout is redirected, e.g. `x=$(nibble choose a b c)`, point it at the
+/// controlling terminal while the TUI is shown, so only the result printed after
+/// `restore` ends up in the redirect. Returns the terminal to draw on.
+///
+/// crossterm asks for the cursor position on stdout, so swapping the backend's
+/// writer alone isn't enough.
+#[cfg(unix)]
+fn draw_on_tty() -> Option<File> {
+    use std::os::fd::AsRawFd;
+
+    if stdout().is_terminal() || SAVED_STDOUT.load(Ordering::SeqCst) >= 0 {
+        return None;
+    }
+    let tty = std::fs::OpenOptions::new()
+        .read(true)
+        .write(true)
+        .open("/dev/tty")
+        .ok()?;
+
+    stdout().flush().ok();
+    // SAFETY: plain descriptor calls on descriptors owned by this process
+    unsafe {
+        let saved = libc::dup(libc::STDOUT_FILENO);
+        if saved < 0 {
+            return None;
+        }
+        if libc::dup2(tty.as_raw_fd(), libc::STDOUT_FILENO) < 0 {
+            libc::close(saved);
+            return None;
+        }
+        SAVED_STDOUT.store(saved, Ordering::SeqCst);
+    }
+    Some(tty)
+}
+
+#[cfg(not(unix))]
+fn draw_on_tty() -> Option<File> {
+    None
+}
+
+/// Point stdout back at wherever it was redirected to
+#[cfg(unix)]
+fn restore_stdout() {
+    let saved = SAVED_STDOUT.swap(-1, Ordering::SeqCst);
+    if saved < 0 {
+        return;
+    }
+
+    stdout().flush().ok();
+    // SAFETY: `saved` is the descriptor duplicated in `draw_on_tty`
+    unsafe {
+        libc::dup2(saved, libc::STDOUT_FILENO);
+        libc::close(saved);
+    }
+}
+
+#[cfg(not(unix))]
+fn restore_stdout() {}
+
+/// Whether keys can be read from a terminal, which on Unix works even when stdin is piped
+pub fn tty_available() -> bool {
+    stdin().is_terminal() || cfg!(not(unix)) || File::open("/dev/tty").is_ok()
+}
+
+/// Read all of stdin if it is piped, or `None` when stdin is an interactive terminal
+pub fn read_piped_stdin() -> Result<Option<String>> {
+    let mut stdin = stdin();
+    if stdin.is_terminal() {
+        return Ok(None);
+    }
+
+    let mut content = String::new();
+    stdin.read_to_string(&mut content)?;
+    Ok(Some(content))
+}
+
+/// The options given as arguments, or else the non-empty lines piped to stdin
+pub fn read_options(options: &[String]) -> Result<Vec<String>> {
+    if !options.is_empty() {
+        return Ok(options.to_vec());
+    }
+
+    Ok(read_piped_stdin()?
+        .map(|content| {
+            content
+                .lines()
+                .filter(|line| !line.trim().is_empty())
+                .map(|line| line.to_string())
+                .collect()
+        })
+        .unwrap_or_default())
+}
+
+/// How many options a list widget lets you select
+#[derive(Args, Debug)]
+pub struct SelectLimit {
+    /// Maximum number of options that can be selected
+    #[arg(long, default_value = "1", conflicts_with = "no_limit")]
+    pub limit: usize,
+
+    /// Allow any number of options to be selected
+    #[arg(long)]
+    pub no_limit: bool,
+}
+
+impl SelectLimit {
+    pub fn validate(&self) -> Result<()> {
+        if self.limit == 0 && !self.no_limit {
+            return Err(NibbleError::ConfigError(
+                "Limit must be greater than 0".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Most options that can be selected at once
+    pub fn max(&self) -> usize {
+        if self.no_limit {
+            usize::MAX
+        } else {
+            self.limit
+        }
+    }
+
+    pub fn is_multi(&self) -> bool {
+        self.max() > 1
+    }
+}
+
+/// Send each line read from `reader` on `tx` from a background thread
+pub fn forward_lines<R: Read + Send + 'static>(reader: R, tx: Sender<String>) {
+    std::thread::spawn(move || {
+        for line in BufReader::new(reader).lines() {
+            let Ok(line) = line else { break };
+            if tx.send(line).is_err() {
+                break;
+            }
+        }
+    });
+}
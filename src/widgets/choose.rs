@@ -0,0 +1,236 @@
+use crate::{
+    error::{NibbleError, Result},
+    style::StyleConfig,
+    tui,
+};
+use clap::Args;
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyModifiers};
+use ratatui::{
+    Frame,
+    style::Modifier,
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, ListState},
+};
+
+#[derive(Args, Debug)]
+pub struct ChooseArgs {
+    /// Options to choose from (read from stdin, one per line, if omitted)
+    pub options: Vec<String>,
+
+    #[command(flatten)]
+    pub limit: tui::SelectLimit,
+
+    /// Title of the choose block
+    #[arg(short, long, default_value = "")]
+    pub title: String,
+
+    /// Height of the choose widget in lines
+    #[arg(long, default_value = "10")]
+    pub height: u16,
+
+    /// Prefix shown in front of the option under the cursor
+    #[arg(long, default_value = "> ")]
+    pub cursor: String,
+
+    /// Prefix shown in front of selected options (multi-select only)
+    #[arg(long, default_value = "[x] ")]
+    pub selected_prefix: String,
+
+    /// Prefix shown in front of unselected options (multi-select only)
+    #[arg(long, default_value = "[ ] ")]
+    pub unselected_prefix: String,
+
+    #[command(flatten)]
+    pub style: StyleConfig,
+}
+
+pub fn run(args: ChooseArgs) -> anyhow::Result<()> {
+    // Validate args
+    if args.height == 0 {
+        return Err(
+            NibbleError::InvalidDimensions("Height must be greater than 0".to_string()).into(),
+        );
+    }
+
+    args.limit.validate()?;
+
+    let options = tui::read_options(&args.options)?;
+
+    if options.is_empty() {
+        return Err(NibbleError::ConfigError(
+            "No options provided (pass them as arguments or via stdin)".to_string(),
+        )
+        .into());
+    }
+
+    let mut terminal = tui::init_inline(args.height)?;
+    let mut state = ListState::default().with_selected(Some(0));
+    let mut selected = vec![false; options.len()];
+
+    // Rows taken by the block's borders or title
+    let chrome = if args.style.border != "none" {
+        2
+    } else if !args.title.is_empty() {
+        1
+    } else {
+        0
+    };
+
+    let result = loop {
+        let viewport = terminal
+            .draw(|frame| {
+                if let Err(e) = render(frame, &args, &options, &selected, &mut state) {
+                    eprintln!("Render error: {}", e);
+                }
+            })
+            .map_err(|e| NibbleError::RenderError(e.to_string()))?
+            .buffer
+            .area;
+
+        // Number of options visible at once, used for page jumps
+        let page = viewport.height.saturating_sub(chrome).max(1) as usize;
+
+        if let Event::Key(key) = event::read()? {
+            let cursor = state.selected().unwrap_or(0);
+            let last = options.len() - 1;
+
+            match key.code {
+                // Navigation
+                KeyCode::Up | KeyCode::Char('k') => {
+                    state.select(Some(if cursor == 0 { last } else { cursor - 1 }))
+                }
+                KeyCode::Down | KeyCode::Char('j') => {
+                    state.select(Some(if cursor == last { 0 } else { cursor + 1 }))
+                }
+                KeyCode::PageUp => state.select(Some(cursor.saturating_sub(page))),
+                KeyCode::PageDown => state.select(Some((cursor + page).min(last))),
+                KeyCode::Home | KeyCode::Char('g') => state.select(Some(0)),
+                KeyCode::End | KeyCode::Char('G') => state.select(Some(last)),
+
+                // Toggle selection
+                KeyCode::Char(' ') | KeyCode::Tab | KeyCode::Char('x') if args.limit.is_multi() => {
+                    let count = selected.iter().filter(|s| **s).count();
+                    if selected[cursor] || count < args.limit.max() {
+                        selected[cursor] = !selected[cursor];
+                    }
+                }
+                KeyCode::Char('a') if args.limit.no_limit => {
+                    let all = selected.iter().all(|s| *s);
+                    selected.iter_mut().for_each(|s| *s = !all);
+                }
+
+                // Submit current selection
+                KeyCode::Enter => {
+                    let chosen: Vec<&str> = if selected.iter().any(|s| *s) {
+                        options
+                            .iter()
+                            .zip(&selected)
+                            .filter(|(_, s)| **s)
+                            .map(|(o, _)| o.as_str())
+                            .collect()
+                    } else {
+                        vec![options[cursor].as_str()]
+                    };
+                    break Some(chosen);
+                }
+
+                // Cancel
+                KeyCode::Esc | KeyCode::Char('q') => break None,
+                KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => break None,
+
+                _ => {}
+            }
+        }
+    };
+
+    // Clear and restore terminal before printing the selection
+    terminal.clear()?;
+    tui::restore()?;
+
+    match result {
+        Some(chosen) => {
+            for item in chosen {
+                println!("{}", item);
+            }
+            Ok(())
+        }
+        None => std::process::exit(1),
+    }
+}
+
+fn render(
+    frame: &mut Frame,
+    args: &ChooseArgs,
+    options: &[String],
+    selected: &[bool],
+    state: &mut ListState,
+) -> Result<()> {
+    let area = frame.area();
+    let text_style = args.style.text_style()?;
+    let cursor = state.selected().unwrap_or(0);
+    let blank_cursor = " ".repeat(args.cursor.chars().count());
+
+    let items: Vec<ListItem> = options
+        .iter()
+        .enumerate()
+        .map(|(i, option)| {
+            let mut spans = vec![Span::raw(if i == cursor {
+                args.cursor.as_str()
+            } else {
+                blank_cursor.as_str()
+            })];
+
+            if args.limit.is_multi() {
+                spans.push(Span::raw(if selected[i] {
+                    args.selected_prefix.as_str()
+                } else {
+                    args.unselected_prefix.as_str()
+                }));
+            }
+
+            let option_style = if selected[i] {
+                text_style.add_modifier(Modifier::BOLD)
+            } else {
+                text_style
+            };
+            spans.push(Span::styled(option.as_str(), option_style));
+
+            ListItem::new(Line::from(spans)).style(text_style)
+        })
+        .collect();
+
+    let mut list = List::new(items).highlight_style(text_style.add_modifier(Modifier::REVERSED));
+
+    // Add block if title or border is specified
+    if !args.title.is_empty() || args.style.border != "none" {
+        let border_type = args.style.border_type()?;
+        let border_style = args.style.border_style()?;
+
+        let mut title = args.title.clone();
+        if args.limit.is_multi() {
+            let count = selected.iter().filter(|s| **s).count();
+            let limit = if args.limit.no_limit {
+                String::new()
+            } else {
+                format!("/{}", args.limit.limit)
+            };
+            title = format!("{} ({}{} selected)", title, count, limit)
+                .trim_start()
+                .to_string();
+        }
+
+        let block = Block::default()
+            .title(title)
+            .borders(if args.style.border == "none" {
+                Borders::NONE
+            } else {
+                Borders::ALL
+            })
+            .border_type(border_type)
+            .border_style(border_style);
+        list = list.block(block);
+    }
+
+    frame.render_stateful_widget(list, area, state);
+    Ok(())
+}
@@ -0,0 +1,332 @@
+use crate::{
+    error::{NibbleError, Result},
+    style::StyleConfig,
+    tui,
+};
+use clap::Args;
+use glob::Pattern;
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyModifiers};
+use ratatui::{
+    Frame,
+    layout::{Constraint, Layout},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph},
+};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+#[derive(Args, Debug)]
+pub struct FileArgs {
+    /// Directory to start browsing from
+    #[arg(default_value = ".")]
+    pub path: String,
+
+    /// Allow files to be selected (default unless --directory is given)
+    #[arg(long)]
+    pub file: bool,
+
+    /// Allow directories to be selected
+    #[arg(long)]
+    pub directory: bool,
+
+    /// Show hidden files and directories
+    #[arg(short, long)]
+    pub all: bool,
+
+    /// Only show files matching this glob pattern (can be used multiple times)
+    #[arg(short = 'g', long)]
+    pub pattern: Vec<String>,
+
+    /// Title of the file picker block (defaults to the current directory)
+    #[arg(short, long)]
+    pub title: Option<String>,
+
+    /// Height of the file picker in lines
+    #[arg(long, default_value = "10")]
+    pub height: u16,
+
+    #[command(flatten)]
+    pub style: StyleConfig,
+}
+
+impl FileArgs {
+    fn files_selectable(&self) -> bool {
+        self.file || !self.directory
+    }
+}
+
+struct Entry {
+    name: String,
+    path: PathBuf,
+    is_dir: bool,
+}
+
+pub fn run(args: FileArgs) -> anyhow::Result<()> {
+    // Validate args
+    if args.height == 0 {
+        return Err(
+            NibbleError::InvalidDimensions("Height must be greater than 0".to_string()).into(),
+        );
+    }
+
+    let patterns = args
+        .pattern
+        .iter()
+        .map(|p| {
+            Pattern::new(p)
+                .map_err(|e| NibbleError::ConfigError(format!("Invalid pattern '{}': {}", p, e)))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let mut current_dir = fs::canonicalize(&args.path)
+        .map_err(|e| NibbleError::ConfigError(format!("Failed to open '{}': {}", args.path, e)))?;
+
+    if !current_dir.is_dir() {
+        return Err(NibbleError::ConfigError(format!("'{}' is not a directory", args.path)).into());
+    }
+
+    let mut entries = read_entries(&current_dir, &args, &patterns)?;
+    let mut state = ListState::default().with_selected(Some(0));
+    // Why the last directory couldn't be opened
+    let mut message: Option<String> = None;
+
+    let mut terminal = tui::init_inline(args.height)?;
+
+    // Rows taken by the block's borders or title
+    let chrome = if args.style.border != "none" {
+        2
+    } else if args.title.is_some() {
+        1
+    } else {
+        0
+    };
+
+    let result = loop {
+        let viewport = terminal
+            .draw(|frame| {
+                let message = message.as_deref();
+                if let Err(e) = render(frame, &args, &current_dir, &entries, &mut state, message) {
+                    eprintln!("Render error: {}", e);
+                }
+            })
+            .map_err(|e| NibbleError::RenderError(e.to_string()))?
+            .buffer
+            .area;
+
+        // Number of entries visible at once, used for page jumps
+        let chrome = chrome + u16::from(message.is_some());
+        let page = viewport.height.saturating_sub(chrome).max(1) as usize;
+
+        if let Event::Key(key) = event::read()? {
+            let cursor = state.selected().unwrap_or(0);
+            let last = entries.len().saturating_sub(1);
+            let entry = entries.get(cursor);
+            message = None;
+
+            match key.code {
+                // Navigation
+                KeyCode::Up | KeyCode::Char('k') => state.select(Some(cursor.saturating_sub(1))),
+                KeyCode::Down | KeyCode::Char('j') => state.select(Some((cursor + 1).min(last))),
+                KeyCode::PageUp => state.select(Some(cursor.saturating_sub(page))),
+                KeyCode::PageDown => state.select(Some((cursor + page).min(last))),
+                KeyCode::Home | KeyCode::Char('g') => state.select(Some(0)),
+                KeyCode::End | KeyCode::Char('G') => state.select(Some(last)),
+
+                // Descend into a directory
+                KeyCode::Right | KeyCode::Char('l') => {
+                    if let Some(entry) = entry.filter(|e| e.is_dir) {
+                        let path = entry.path.clone();
+                        if let Some(listed) = open_dir(&path, &args, &patterns, &mut message) {
+                            current_dir = path;
+                            entries = listed;
+                            state.select(Some(0));
+                        }
+                    }
+                }
+
+                // Ascend to the parent, keeping the directory we came from selected
+                KeyCode::Left | KeyCode::Char('h') | KeyCode::Backspace => {
+                    if let Some(parent) = current_dir.parent().map(Path::to_path_buf)
+                        && let Some(listed) = open_dir(&parent, &args, &patterns, &mut message)
+                    {
+                        let previous = std::mem::replace(&mut current_dir, parent);
+                        entries = listed;
+                        let index = entries.iter().position(|e| e.path == previous);
+                        state.select(Some(index.unwrap_or(0)));
+                    }
+                }
+
+                // Select the entry, or descend if directories can't be selected
+                KeyCode::Enter => match entry {
+                    Some(entry) if entry.is_dir && !args.directory => {
+                        let path = entry.path.clone();
+                        if let Some(listed) = open_dir(&path, &args, &patterns, &mut message) {
+                            current_dir = path;
+                            entries = listed;
+                            state.select(Some(0));
+                        }
+                    }
+                    Some(entry) if entry.is_dir || args.files_selectable() => {
+                        break Some(entry.path.clone());
+                    }
+                    _ => {}
+                },
+
+                // Cancel
+                KeyCode::Esc | KeyCode::Char('q') => break None,
+                KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => break None,
+
+                _ => {}
+            }
+        }
+    };
+
+    // Clear and restore terminal before printing the selection
+    terminal.clear()?;
+    tui::restore()?;
+
+    match result {
+        Some(path) => {
+            println!("{}", path.display());
+            Ok(())
+        }
+        None => std::process::exit(1),
+    }
+}
+
+/// List `dir` to move into it, or keep the reason it can't be read in `message`
+fn open_dir(
+    dir: &Path,
+    args: &FileArgs,
+    patterns: &[Pattern],
+    message: &mut Option<String>,
+) -> Option<Vec<Entry>> {
+    match read_entries(dir, args, patterns) {
+        Ok(entries) => Some(entries),
+        Err(NibbleError::ConfigError(e)) => {
+            *message = Some(e);
+            None
+        }
+        Err(e) => {
+            *message = Some(e.to_string());
+            None
+        }
+    }
+}
+
+/// List a directory: directories first, then files, each sorted by name
+fn read_entries(dir: &Path, args: &FileArgs, patterns: &[Pattern]) -> Result<Vec<Entry>> {
+    let mut entries: Vec<Entry> = fs::read_dir(dir)
+        .map_err(|e| {
+            NibbleError::ConfigError(format!("Failed to read '{}': {}", dir.display(), e))
+        })?
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| {
+            let name = entry.file_name().to_string_lossy().to_string();
+            let path = entry.path();
+            // Follow symlinks so linked directories can be browsed too
+            let is_dir = path.is_dir();
+
+            if !args.all && name.starts_with('.') {
+                return None;
+            }
+
+            // Directories stay visible for navigation; patterns only filter files
+            if !is_dir {
+                if !args.files_selectable() {
+                    return None;
+                }
+                if !patterns.is_empty() && !patterns.iter().any(|p| p.matches(&name)) {
+                    return None;
+                }
+            }
+
+            Some(Entry { name, path, is_dir })
+        })
+        .collect();
+
+    entries.sort_by(|a, b| {
+        b.is_dir
+            .cmp(&a.is_dir)
+            .then_with(|| a.name.to_lowercase().cmp(&b.name.to_lowercase()))
+    });
+
+    Ok(entries)
+}
+
+fn render(
+    frame: &mut Frame,
+    args: &FileArgs,
+    current_dir: &Path,
+    entries: &[Entry],
+    state: &mut ListState,
+    message: Option<&str>,
+) -> Result<()> {
+    let mut area = frame.area();
+
+    // Reserve the last row for an error message
+    if let Some(message) = message {
+        let [list_area, message_area] =
+            Layout::vertical([Constraint::Min(1), Constraint::Length(1)]).areas(area);
+        frame.render_widget(
+            Paragraph::new(message).style(Style::default().fg(Color::Red)),
+            message_area,
+        );
+        area = list_area;
+    }
+    let text_style = args.style.text_style()?;
+    let dir_style = text_style.add_modifier(Modifier::BOLD);
+
+    let items: Vec<ListItem> = if entries.is_empty() {
+        vec![ListItem::new(Span::styled(
+            "(empty)",
+            Style::default().fg(Color::DarkGray),
+        ))]
+    } else {
+        entries
+            .iter()
+            .map(|entry| {
+                let line = if entry.is_dir {
+                    Line::from(Span::styled(format!("{}/", entry.name), dir_style))
+                } else {
+                    Line::from(Span::styled(entry.name.as_str(), text_style))
+                };
+                ListItem::new(line)
+            })
+            .collect()
+    };
+
+    let mut list = List::new(items)
+        .highlight_symbol("> ")
+        .highlight_spacing(ratatui::widgets::HighlightSpacing::Always)
+        .highlight_style(text_style.add_modifier(Modifier::REVERSED));
+
+    // Add block if title or border is specified
+    if args.title.is_some() || args.style.border != "none" {
+        let border_type = args.style.border_type()?;
+        let border_style = args.style.border_style()?;
+        let title = args
+            .title
+            .clone()
+            .unwrap_or_else(|| current_dir.display().to_string());
+
+        let block = Block::default()
+            .title(title)
+            .borders(if args.style.border == "none" {
+                Borders::NONE
+            } else {
+                Borders::ALL
+            })
+            .border_type(border_type)
+            .border_style(border_style);
+        list = list.block(block);
+    }
+
+    if entries.is_empty() {
+        frame.render_widget(list, area);
+    } else {
+        frame.render_stateful_widget(list, area, state);
+    }
+    Ok(())
+}
@@ -0,0 +1,429 @@
+use crate::{
+    error::{NibbleError, Result},
+    style::{StyleConfig, parse_color},
+    tui,
+};
+use clap::Args;
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEvent, KeyModifiers};
+use ratatui::{
+    Frame,
+    layout::{Constraint, Layout},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph},
+};
+use tui_input::{Input, backend::crossterm::EventHandler};
+
+#[derive(Args, Debug)]
+pub struct FilterArgs {
+    /// Options to filter (read from stdin, one per line, if omitted)
+    pub options: Vec<String>,
+
+    #[command(flatten)]
+    pub limit: tui::SelectLimit,
+
+    /// Only allow submitting an option that matches the query
+    #[arg(long)]
+    pub strict: bool,
+
+    /// Placeholder text when the query is empty
+    #[arg(short, long, default_value = "Filter...")]
+    pub placeholder: String,
+
+    /// Prompt text to display before the query
+    #[arg(short = 'r', long, default_value = "> ")]
+    pub prompt: String,
+
+    /// Initial query
+    #[arg(short = 'v', long, default_value = "")]
+    pub value: String,
+
+    /// Title of the filter block
+    #[arg(short, long, default_value = "")]
+    pub title: String,
+
+    /// Height of the filter widget in lines
+    #[arg(long, default_value = "10")]
+    pub height: u16,
+
+    /// Color used to highlight matched characters
+    #[arg(long, default_value = "cyan")]
+    pub match_color: String,
+
+    #[command(flatten)]
+    pub style: StyleConfig,
+}
+
+/// An option that matched the current query
+struct Match {
+    index: usize,
+    score: i64,
+    positions: Vec<usize>,
+}
+
+pub fn run(args: FilterArgs) -> anyhow::Result<()> {
+    // Validate args
+    if args.height < 2 {
+        return Err(NibbleError::InvalidDimensions("Height must be at least 2".to_string()).into());
+    }
+
+    args.limit.validate()?;
+
+    // Fail early on an invalid match color instead of on every render
+    parse_color(&args.match_color)?;
+
+    let options = tui::read_options(&args.options)?;
+
+    if options.is_empty() {
+        return Err(NibbleError::ConfigError(
+            "No options provided (pass them as arguments or via stdin)".to_string(),
+        )
+        .into());
+    }
+
+    let mut terminal = tui::init_inline(args.height)?;
+    let mut input = Input::default().with_value(args.value.clone());
+    let mut matches = filter_options(&options, input.value());
+    let mut state = ListState::default().with_selected(Some(0));
+    let mut selected = vec![false; options.len()];
+
+    let result = loop {
+        terminal
+            .draw(|frame| {
+                if let Err(e) = render(
+                    frame, &args, &input, &options, &matches, &selected, &mut state,
+                ) {
+                    eprintln!("Render error: {}", e);
+                }
+            })
+            .map_err(|e| NibbleError::RenderError(e.to_string()))?;
+
+        if let Event::Key(key) = event::read()? {
+            match handle_key_event(key, &args, &mut state, &matches, &mut selected) {
+                FilterAction::Continue => {}
+                FilterAction::Edit => {
+                    input.handle_event(&Event::Key(key));
+                    matches = filter_options(&options, input.value());
+                    state.select(Some(0));
+                }
+                FilterAction::Submit => {
+                    if selected.iter().any(|s| *s) {
+                        break Some(
+                            options
+                                .iter()
+                                .zip(&selected)
+                                .filter(|(_, s)| **s)
+                                .map(|(o, _)| o.clone())
+                                .collect::<Vec<_>>(),
+                        );
+                    }
+
+                    if let Some(m) = state.selected().and_then(|i| matches.get(i)) {
+                        break Some(vec![options[m.index].clone()]);
+                    }
+
+                    // Nothing matches: submit the raw query unless strict
+                    if !args.strict && !input.value().is_empty() {
+                        break Some(vec![input.value().to_string()]);
+                    }
+                }
+                FilterAction::Cancel => break None,
+            }
+        }
+    };
+
+    // Clear and restore terminal before printing the selection
+    terminal.clear()?;
+    tui::restore()?;
+
+    match result {
+        Some(chosen) => {
+            for item in chosen {
+                println!("{}", item);
+            }
+            Ok(())
+        }
+        None => std::process::exit(1),
+    }
+}
+
+enum FilterAction {
+    Continue,
+    Edit,
+    Submit,
+    Cancel,
+}
+
+fn handle_key_event(
+    key: KeyEvent,
+    args: &FilterArgs,
+    state: &mut ListState,
+    matches: &[Match],
+    selected: &mut [bool],
+) -> FilterAction {
+    let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+    let cursor = state.selected().unwrap_or(0);
+    let last = matches.len().saturating_sub(1);
+
+    match key.code {
+        KeyCode::Enter => FilterAction::Submit,
+        KeyCode::Esc => FilterAction::Cancel,
+        KeyCode::Char('c') if ctrl => FilterAction::Cancel,
+
+        // Navigation
+        KeyCode::Up => {
+            state.select(Some(cursor.saturating_sub(1)));
+            FilterAction::Continue
+        }
+        KeyCode::Char('p') | KeyCode::Char('k') if ctrl => {
+            state.select(Some(cursor.saturating_sub(1)));
+            FilterAction::Continue
+        }
+        KeyCode::Down => {
+            state.select(Some((cursor + 1).min(last)));
+            FilterAction::Continue
+        }
+        KeyCode::Char('n') | KeyCode::Char('j') if ctrl => {
+            state.select(Some((cursor + 1).min(last)));
+            FilterAction::Continue
+        }
+
+        // Toggle selection
+        KeyCode::Tab if args.limit.is_multi() => {
+            if let Some(m) = matches.get(cursor) {
+                let count = selected.iter().filter(|s| **s).count();
+                if selected[m.index] || count < args.limit.max() {
+                    selected[m.index] = !selected[m.index];
+                }
+                state.select(Some((cursor + 1).min(last)));
+            }
+            FilterAction::Continue
+        }
+
+        _ => FilterAction::Edit,
+    }
+}
+
+/// Match every option against the query, best matches first
+fn filter_options(options: &[String], query: &str) -> Vec<Match> {
+    let mut matches: Vec<Match> = options
+        .iter()
+        .enumerate()
+        .filter_map(|(index, option)| {
+            fuzzy_match(query, option).map(|(score, positions)| Match {
+                index,
+                score,
+                positions,
+            })
+        })
+        .collect();
+
+    // Stable sort keeps the input order for equal scores
+    matches.sort_by_key(|m| std::cmp::Reverse(m.score));
+    matches
+}
+
+/// Score `candidate` against `query`, returning the score and matched char positions.
+///
+/// Every query character must appear in order (case-insensitively). Matches at the
+/// start of a word and runs of consecutive characters score higher, gaps score lower.
+fn fuzzy_match(query: &str, candidate: &str) -> Option<(i64, Vec<usize>)> {
+    if query.is_empty() {
+        return Some((0, Vec::new()));
+    }
+
+    let chars: Vec<char> = candidate.chars().collect();
+    let query: Vec<char> = query.chars().flat_map(char::to_lowercase).collect();
+
+    let mut positions: Vec<usize> = Vec::with_capacity(query.len());
+    let mut score = 0i64;
+    let mut next = 0;
+
+    for q in &query {
+        let found = (next..chars.len()).find(|&i| chars[i].to_lowercase().eq(Some(*q)))?;
+
+        score += 16;
+
+        // Reward word boundaries: start of string, after a separator or camelCase hump
+        let prev = found.checked_sub(1).map(|i| chars[i]);
+        match prev {
+            None => score += 12,
+            Some(p) if !p.is_alphanumeric() => score += 10,
+            Some(p) if p.is_lowercase() && chars[found].is_uppercase() => score += 8,
+            _ => {}
+        }
+
+        // Reward consecutive matches, penalize gaps
+        if let Some(&last) = positions.last() {
+            if found == last + 1 {
+                score += 8;
+            } else {
+                score -= (found - last - 1).min(8) as i64;
+            }
+        } else {
+            score -= found.min(8) as i64;
+        }
+
+        positions.push(found);
+        next = found + 1;
+    }
+
+    // Prefer shorter candidates when everything else is equal
+    score -= (chars.len() / 8) as i64;
+
+    Some((score, positions))
+}
+
+fn render(
+    frame: &mut Frame,
+    args: &FilterArgs,
+    input: &Input,
+    options: &[String],
+    matches: &[Match],
+    selected: &[bool],
+    state: &mut ListState,
+) -> Result<()> {
+    let area = frame.area();
+    let text_style = args.style.text_style()?;
+    let match_style = text_style
+        .fg(parse_color(&args.match_color)?)
+        .add_modifier(Modifier::BOLD);
+
+    // Add block if title or border is specified
+    let inner = if !args.title.is_empty() || args.style.border != "none" {
+        let border_type = args.style.border_type()?;
+        let border_style = args.style.border_style()?;
+
+        let mut title = format!("{} ({}/{})", args.title, matches.len(), options.len());
+        if args.limit.is_multi() {
+            let count = selected.iter().filter(|s| **s).count();
+            title = format!("{} ({} selected)", title, count);
+        }
+
+        let block = Block::default()
+            .title(title.trim_start().to_string())
+            .borders(if args.style.border == "none" {
+                Borders::NONE
+            } else {
+                Borders::ALL
+            })
+            .border_type(border_type)
+            .border_style(border_style);
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+        inner
+    } else {
+        area
+    };
+
+    let chunks = Layout::vertical([Constraint::Length(1), Constraint::Min(0)]).split(inner);
+
+    // Query line
+    let mut spans = vec![Span::styled(
+        args.prompt.as_str(),
+        text_style.add_modifier(Modifier::BOLD),
+    )];
+    spans.extend(query_spans(args, input, text_style));
+    frame.render_widget(Paragraph::new(Line::from(spans)), chunks[0]);
+
+    // Matching options
+    let items: Vec<ListItem> = matches
+        .iter()
+        .map(|m| {
+            let mut spans = Vec::new();
+
+            if args.limit.is_multi() {
+                spans.push(Span::raw(if selected[m.index] { "[x] " } else { "[ ] " }));
+            }
+
+            spans.extend(highlight_spans(
+                &options[m.index],
+                &m.positions,
+                text_style,
+                match_style,
+            ));
+            ListItem::new(Line::from(spans)).style(text_style)
+        })
+        .collect();
+
+    let list = List::new(items)
+        .highlight_symbol("> ")
+        .highlight_spacing(ratatui::widgets::HighlightSpacing::Always)
+        .highlight_style(text_style.add_modifier(Modifier::REVERSED));
+
+    frame.render_stateful_widget(list, chunks[1], state);
+    Ok(())
+}
+
+/// Build the query spans with a block cursor, or the placeholder if empty
+fn query_spans<'a>(args: &'a FilterArgs, input: &Input, text_style: Style) -> Vec<Span<'a>> {
+    if input.value().is_empty() {
+        return vec![
+            Span::styled(
+                " ",
+                Style::default()
+                    .fg(Color::DarkGray)
+                    .add_modifier(Modifier::REVERSED),
+            ),
+            Span::styled(
+                args.placeholder.as_str(),
+                Style::default().fg(Color::DarkGray),
+            ),
+        ];
+    }
+
+    let cursor_pos = input.cursor();
+    let chars: Vec<char> = input.value().chars().collect();
+    let mut spans: Vec<Span> = chars
+        .iter()
+        .enumerate()
+        .map(|(i, ch)| {
+            if i == cursor_pos {
+                Span::styled(ch.to_string(), text_style.add_modifier(Modifier::REVERSED))
+            } else {
+                Span::styled(ch.to_string(), text_style)
+            }
+        })
+        .collect();
+
+    // If cursor is at the end, show block cursor
+    if cursor_pos >= chars.len() {
+        spans.push(Span::styled(
+            " ",
+            text_style.add_modifier(Modifier::REVERSED),
+        ));
+    }
+
+    spans
+}
+
+/// Split `text` into spans, styling the chars at `positions` with `match_style`
+fn highlight_spans<'a>(
+    text: &'a str,
+    positions: &[usize],
+    text_style: Style,
+    match_style: Style,
+) -> Vec<Span<'a>> {
+    let mut spans = Vec::new();
+    let mut run_start = 0;
+    let mut run_matched = false;
+
+    for (i, (byte, _)) in text.char_indices().enumerate() {
+        let matched = positions.binary_search(&i).is_ok();
+        if matched != run_matched {
+            if byte > run_start {
+                let style = if run_matched { match_style } else { text_style };
+                spans.push(Span::styled(&text[run_start..byte], style));
+            }
+            run_start = byte;
+            run_matched = matched;
+        }
+    }
+
+    if run_start < text.len() {
+        let style = if run_matched { match_style } else { text_style };
+        spans.push(Span::styled(&text[run_start..], style));
+    }
+
+    spans
+}
@@ -0,0 +1,874 @@
+use crate::{
+    ansi,
+    error::{NibbleError, Result},
+    style::{StyleConfig, parse_color, parse_modifier},
+    tui,
+};
+use clap::Args;
+use pulldown_cmark::{
+    Alignment as TableAlignment, CodeBlockKind, Event, HeadingLevel, Options, Parser, Tag, TagEnd,
+};
+use ratatui::{
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+};
+use unicode_width::UnicodeWidthStr;
+
+#[derive(Args, Debug)]
+pub struct FormatArgs {
+    /// Text to format (read from stdin if omitted); multiple arguments are joined with newlines
+    pub text: Vec<String>,
+
+    /// Input type (markdown, code, template, emoji)
+    #[arg(short = 't', long = "type", default_value = "markdown")]
+    pub format_type: String,
+
+    /// Language used to highlight code (rust, python, js, go, sh, c, json, toml, yaml, sql)
+    #[arg(short, long, default_value = "")]
+    pub language: String,
+
+    /// Color of headings and list bullets
+    #[arg(long, default_value = "cyan")]
+    pub heading_color: String,
+
+    /// Color of inline code
+    #[arg(long, default_value = "yellow")]
+    pub code_color: String,
+
+    /// Color of links
+    #[arg(long, default_value = "blue")]
+    pub link_color: String,
+
+    #[command(flatten)]
+    pub style: StyleConfig,
+}
+
+/// Styles used while formatting
+struct Theme {
+    text: Style,
+    heading: Style,
+    code: Style,
+    link: Style,
+    dim: Style,
+    keyword: Style,
+    string: Style,
+    number: Style,
+    comment: Style,
+    type_name: Style,
+}
+
+impl Theme {
+    fn new(args: &FormatArgs) -> Result<Self> {
+        let text = args.style.text_style()?;
+
+        Ok(Self {
+            text,
+            heading: text
+                .fg(parse_color(&args.heading_color)?)
+                .add_modifier(Modifier::BOLD),
+            code: text.fg(parse_color(&args.code_color)?),
+            link: text
+                .fg(parse_color(&args.link_color)?)
+                .add_modifier(Modifier::UNDERLINED),
+            dim: text.fg(Color::DarkGray),
+            keyword: text.fg(Color::Magenta).add_modifier(Modifier::BOLD),
+            string: text.fg(Color::Green),
+            number: text.fg(Color::Yellow),
+            comment: text.fg(Color::DarkGray).add_modifier(Modifier::ITALIC),
+            type_name: text.fg(Color::Cyan),
+        })
+    }
+}
+
+pub fn run(args: FormatArgs) -> anyhow::Result<()> {
+    let input = if args.text.is_empty() {
+        tui::read_piped_stdin()?.unwrap_or_default()
+    } else {
+        args.text.join("\n")
+    };
+
+    let theme = Theme::new(&args)?;
+
+    let lines = match args.format_type.to_lowercase().as_str() {
+        "markdown" | "md" => render_markdown(&input, &theme),
+        "code" => highlight_code(input.trim_end_matches('\n'), &args.language, &theme),
+        "template" | "tpl" => render_template(input.trim_end_matches('\n'), &theme)?,
+        "emoji" => replace_emoji(input.trim_end_matches('\n'))
+            .lines()
+            .map(|l| Line::styled(l.to_string(), theme.text))
+            .collect(),
+        _ => {
+            return Err(NibbleError::ConfigError(format!(
+                "Unknown format type '{}'. Valid types: markdown, code, template, emoji",
+                args.format_type
+            ))
+            .into());
+        }
+    };
+
+    for line in lines {
+        println!("{}", ansi::line_to_string(&line));
+    }
+
+    Ok(())
+}
+
+struct Table {
+    alignments: Vec<TableAlignment>,
+    rows: Vec<Vec<Vec<Span<'static>>>>,
+    row: Vec<Vec<Span<'static>>>,
+    cell: Vec<Span<'static>>,
+    has_header: bool,
+}
+
+struct MarkdownRenderer<'a> {
+    theme: &'a Theme,
+    lines: Vec<Line<'static>>,
+    spans: Vec<Span<'static>>,
+    /// Inline styles (emphasis, links, ...) currently in effect
+    styles: Vec<Style>,
+    /// Next item number for each nested list (`None` for bullet lists)
+    lists: Vec<Option<u64>>,
+    /// Bullet waiting to be printed on the first line of a list item
+    item_bullet: Option<Span<'static>>,
+    /// Width of the current item's bullet, used to indent continuation lines
+    item_indent: usize,
+    quote_depth: usize,
+    /// Language and contents of the fenced code block being read
+    code_block: Option<(String, String)>,
+    links: Vec<String>,
+    table: Option<Table>,
+}
+
+fn render_markdown(input: &str, theme: &Theme) -> Vec<Line<'static>> {
+    let options =
+        Options::ENABLE_TABLES | Options::ENABLE_STRIKETHROUGH | Options::ENABLE_TASKLISTS;
+
+    let mut renderer = MarkdownRenderer {
+        theme,
+        lines: Vec::new(),
+        spans: Vec::new(),
+        styles: Vec::new(),
+        lists: Vec::new(),
+        item_bullet: None,
+        item_indent: 0,
+        quote_depth: 0,
+        code_block: None,
+        links: Vec::new(),
+        table: None,
+    };
+
+    for event in Parser::new_ext(input, options) {
+        renderer.handle(event);
+    }
+    renderer.flush_line();
+
+    // Drop the blank line that follows the last block
+    while renderer.lines.last().is_some_and(|l| l.width() == 0) {
+        renderer.lines.pop();
+    }
+
+    renderer.lines
+}
+
+impl MarkdownRenderer<'_> {
+    fn style(&self) -> Style {
+        self.styles.last().copied().unwrap_or(self.theme.text)
+    }
+
+    fn push_style(&mut self, f: impl FnOnce(Style) -> Style) {
+        self.styles.push(f(self.style()));
+    }
+
+    fn push_span(&mut self, span: Span<'static>) {
+        match self.table.as_mut() {
+            Some(table) => table.cell.push(span),
+            None => self.spans.push(span),
+        }
+    }
+
+    /// Quote bars and list indentation in front of a line
+    fn prefix(&mut self) -> Vec<Span<'static>> {
+        let mut prefix = Vec::new();
+
+        if self.quote_depth > 0 {
+            prefix.push(Span::styled("│ ".repeat(self.quote_depth), self.theme.dim));
+        }
+
+        if !self.lists.is_empty() {
+            let nesting = "  ".repeat(self.lists.len() - 1);
+            match self.item_bullet.take() {
+                Some(bullet) => {
+                    prefix.push(Span::raw(nesting));
+                    prefix.push(bullet);
+                }
+                None => prefix.push(Span::raw(nesting + &" ".repeat(self.item_indent))),
+            }
+        }
+
+        prefix
+    }
+
+    fn flush_line(&mut self) {
+        if self.spans.is_empty() && self.item_bullet.is_none() {
+            return;
+        }
+
+        let mut spans = self.prefix();
+        spans.append(&mut self.spans);
+        self.lines.push(Line::from(spans));
+    }
+
+    fn blank_line(&mut self) {
+        self.flush_line();
+        if self.lines.last().is_some_and(|l| l.width() > 0) {
+            self.lines.push(Line::default());
+        }
+    }
+
+    fn handle(&mut self, event: Event) {
+        match event {
+            Event::Start(tag) => self.start(tag),
+            Event::End(tag) => self.end(tag),
+            Event::Text(text) => {
+                if let Some((_, code)) = self.code_block.as_mut() {
+                    code.push_str(&text);
+                } else {
+                    let style = self.style();
+                    self.push_span(Span::styled(replace_emoji(&text), style));
+                }
+            }
+            Event::Code(code) => self.push_span(Span::styled(code.to_string(), self.theme.code)),
+            Event::Html(html) | Event::InlineHtml(html) => {
+                // Comments are invisible when rendered, so keep them that way
+                let html = html.trim_end_matches('\n');
+                if !html.trim_start().starts_with("<!--") {
+                    self.push_span(Span::styled(html.to_string(), self.theme.dim));
+                }
+            }
+            Event::SoftBreak => self.push_span(Span::raw(" ")),
+            Event::HardBreak => self.flush_line(),
+            Event::Rule => {
+                self.flush_line();
+                self.lines
+                    .push(Line::styled("─".repeat(40), self.theme.dim));
+                self.blank_line();
+            }
+            Event::TaskListMarker(checked) => {
+                let marker = if checked { "[x] " } else { "[ ] " };
+                self.push_span(Span::styled(marker, self.theme.heading));
+            }
+            Event::FootnoteReference(name) => {
+                self.push_span(Span::styled(format!("[^{}]", name), self.theme.dim))
+            }
+            _ => {}
+        }
+    }
+
+    fn start(&mut self, tag: Tag) {
+        match tag {
+            Tag::Heading { level, .. } => {
+                self.flush_line();
+                let level_style = if level == HeadingLevel::H1 {
+                    self.theme.heading.add_modifier(Modifier::UNDERLINED)
+                } else {
+                    self.theme.heading
+                };
+                self.styles.push(level_style);
+                let marker = "#".repeat(level as usize);
+                self.push_span(Span::styled(format!("{} ", marker), level_style));
+            }
+            Tag::BlockQuote(_) => {
+                self.flush_line();
+                self.quote_depth += 1;
+            }
+            Tag::CodeBlock(kind) => {
+                self.flush_line();
+                let language = match kind {
+                    CodeBlockKind::Fenced(info) => {
+                        info.split_whitespace().next().unwrap_or("").to_string()
+                    }
+                    CodeBlockKind::Indented => String::new(),
+                };
+                self.code_block = Some((language, String::new()));
+            }
+            Tag::List(start) => {
+                self.flush_line();
+                self.lists.push(start);
+            }
+            Tag::Item => {
+                self.flush_line();
+                let nested = self.lists.len() > 1;
+                let bullet = match self.lists.last_mut() {
+                    Some(Some(number)) => {
+                        *number += 1;
+                        format!("{}. ", *number - 1)
+                    }
+                    _ if nested => "◦ ".to_string(),
+                    _ => "• ".to_string(),
+                };
+                self.item_indent = bullet.width();
+                self.item_bullet = Some(Span::styled(bullet, self.theme.heading));
+            }
+            Tag::Emphasis => self.push_style(|s| s.add_modifier(Modifier::ITALIC)),
+            Tag::Strong => self.push_style(|s| s.add_modifier(Modifier::BOLD)),
+            Tag::Strikethrough => self.push_style(|s| s.add_modifier(Modifier::CROSSED_OUT)),
+            Tag::Link { dest_url, .. } => {
+                self.styles.push(self.theme.link);
+                self.links.push(dest_url.to_string());
+            }
+            Tag::Image { .. } => {
+                self.push_span(Span::styled("[image: ", self.theme.dim));
+                self.styles.push(self.theme.dim);
+            }
+            Tag::Table(alignments) => {
+                self.flush_line();
+                self.table = Some(Table {
+                    alignments,
+                    rows: Vec::new(),
+                    row: Vec::new(),
+                    cell: Vec::new(),
+                    has_header: false,
+                });
+            }
+            _ => {}
+        }
+    }
+
+    fn end(&mut self, tag: TagEnd) {
+        match tag {
+            TagEnd::Paragraph => {
+                if self.lists.is_empty() {
+                    self.blank_line();
+                } else {
+                    self.flush_line();
+                }
+            }
+            TagEnd::Heading(_) => {
+                self.styles.pop();
+                self.blank_line();
+            }
+            TagEnd::BlockQuote(_) => {
+                self.flush_line();
+                self.quote_depth -= 1;
+                if self.quote_depth == 0 {
+                    self.blank_line();
+                }
+            }
+            TagEnd::CodeBlock => {
+                if let Some((language, code)) = self.code_block.take() {
+                    for line in highlight_code(code.trim_end_matches('\n'), &language, self.theme) {
+                        let mut spans = self.prefix();
+                        spans.push(Span::raw("  "));
+                        spans.extend(line.spans);
+                        self.lines.push(Line::from(spans));
+                    }
+                }
+                if self.lists.is_empty() {
+                    self.blank_line();
+                }
+            }
+            TagEnd::List(_) => {
+                self.flush_line();
+                self.lists.pop();
+                if self.lists.is_empty() {
+                    self.blank_line();
+                }
+            }
+            TagEnd::Item => self.flush_line(),
+            TagEnd::Emphasis | TagEnd::Strong | TagEnd::Strikethrough => {
+                self.styles.pop();
+            }
+            TagEnd::Link => {
+                self.styles.pop();
+                if let Some(url) = self.links.pop() {
+                    // Autolinks already show the URL as their text
+                    let spans = match self.table.as_ref() {
+                        Some(table) => &table.cell,
+                        None => &self.spans,
+                    };
+                    let shown = spans
+                        .last()
+                        .is_some_and(|s| s.content.as_ref() == url.as_str());
+                    if !shown && !url.starts_with('#') {
+                        self.push_span(Span::styled(format!(" ({})", url), self.theme.dim));
+                    }
+                }
+            }
+            TagEnd::Image => {
+                self.styles.pop();
+                self.push_span(Span::styled("]", self.theme.dim));
+            }
+            TagEnd::TableCell => {
+                if let Some(table) = self.table.as_mut() {
+                    let cell = std::mem::take(&mut table.cell);
+                    table.row.push(cell);
+                }
+            }
+            TagEnd::TableHead | TagEnd::TableRow => {
+                if let Some(table) = self.table.as_mut() {
+                    let row = std::mem::take(&mut table.row);
+                    table.rows.push(row);
+                    table.has_header |= tag == TagEnd::TableHead;
+                }
+            }
+            TagEnd::Table => {
+                if let Some(table) = self.table.take() {
+                    for line in render_table(&table, self.theme) {
+                        let mut spans = self.prefix();
+                        spans.extend(line.spans);
+                        self.lines.push(Line::from(spans));
+                    }
+                }
+                self.blank_line();
+            }
+            _ => {}
+        }
+    }
+}
+
+fn render_table(table: &Table, theme: &Theme) -> Vec<Line<'static>> {
+    let cell_width = |cell: &[Span]| cell.iter().map(|s| s.width()).sum::<usize>();
+    let num_cols = table.rows.iter().map(|r| r.len()).max().unwrap_or(0);
+
+    let widths: Vec<usize> = (0..num_cols)
+        .map(|col| {
+            table
+                .rows
+                .iter()
+                .filter_map(|row| row.get(col))
+                .map(|cell| cell_width(cell))
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+
+    let mut lines = Vec::new();
+
+    for (index, row) in table.rows.iter().enumerate() {
+        let is_header = table.has_header && index == 0;
+        let mut spans = Vec::new();
+
+        for (col, width) in widths.iter().enumerate() {
+            if col > 0 {
+                spans.push(Span::styled(" │ ", theme.dim));
+            }
+
+            let cell = row.get(col).map(Vec::as_slice).unwrap_or(&[]);
+            let space = width - cell_width(cell);
+            let (before, after) = match table.alignments.get(col) {
+                Some(TableAlignment::Right) => (space, 0),
+                Some(TableAlignment::Center) => (space / 2, space - space / 2),
+                _ => (0, space),
+            };
+
+            spans.push(Span::raw(" ".repeat(before)));
+            for span in cell {
+                let span = span.clone();
+                spans.push(if is_header {
+                    span.patch_style(theme.heading)
+                } else {
+                    span
+                });
+            }
+            spans.push(Span::raw(" ".repeat(after)));
+        }
+
+        lines.push(Line::from(spans));
+
+        if is_header {
+            let separator = widths
+                .iter()
+                .map(|w| "─".repeat(*w))
+                .collect::<Vec<_>>()
+                .join("─┼─");
+            lines.push(Line::from(Span::styled(separator, theme.dim)));
+        }
+    }
+
+    lines
+}
+
+/// Just enough of a language's lexical rules to colour it
+struct Syntax {
+    /// Whitespace-separated keywords
+    keywords: &'static str,
+    line_comments: &'static [&'static str],
+    block_comment: Option<(&'static str, &'static str)>,
+    quotes: &'static [char],
+}
+
+fn syntax_for(language: &str) -> Syntax {
+    match language.to_lowercase().as_str() {
+        "rust" | "rs" => Syntax {
+            keywords: "as async await break const continue crate dyn else enum extern false fn for if impl in let loop match mod move mut pub ref return self Self static struct super trait true type unsafe use where while",
+            line_comments: &["//"],
+            block_comment: Some(("/*", "*/")),
+            quotes: &['"'],
+        },
+        "python" | "py" => Syntax {
+            keywords: "False None True and as assert async await break class continue def del elif else except finally for from global if import in is lambda nonlocal not or pass raise return self try while with yield",
+            line_comments: &["#"],
+            block_comment: None,
+            quotes: &['"', '\''],
+        },
+        "javascript" | "js" | "jsx" | "typescript" | "ts" | "tsx" => Syntax {
+            keywords: "async await break case catch class const continue default delete do else export extends false finally for from function if import in instanceof interface let new null of return static super switch this throw true try type typeof undefined var void while yield",
+            line_comments: &["//"],
+            block_comment: Some(("/*", "*/")),
+            quotes: &['"', '\'', '`'],
+        },
+        "go" | "golang" => Syntax {
+            keywords: "break case chan const continue default defer else fallthrough false for func go goto if import interface map nil package range return select struct switch true type var",
+            line_comments: &["//"],
+            block_comment: Some(("/*", "*/")),
+            quotes: &['"', '\'', '`'],
+        },
+        "sh" | "bash" | "zsh" | "shell" | "console" => Syntax {
+            keywords: "case do done echo elif else esac exit export fi for function if in local read return set then until while",
+            line_comments: &["#"],
+            block_comment: None,
+            quotes: &['"', '\''],
+        },
+        "c" | "h" | "cpp" | "c++" | "cc" | "hpp" | "java" | "cs" | "csharp" => Syntax {
+            keywords: "auto bool break case catch char class const continue default do double else enum extends false final float for if import include int long namespace new null nullptr private protected public return short signed sizeof static struct switch template this throw true try typedef union unsigned using void volatile while",
+            line_comments: &["//"],
+            block_comment: Some(("/*", "*/")),
+            quotes: &['"', '\''],
+        },
+        "json" => Syntax {
+            keywords: "true false null",
+            line_comments: &[],
+            block_comment: None,
+            quotes: &['"'],
+        },
+        "toml" | "yaml" | "yml" | "ini" | "conf" => Syntax {
+            keywords: "true false null yes no on off",
+            line_comments: &["#", ";"],
+            block_comment: None,
+            quotes: &['"', '\''],
+        },
+        "sql" => Syntax {
+            keywords: "and as by create delete desc distinct drop from group having insert into join left limit not null on or order select set table update values where",
+            line_comments: &["--"],
+            block_comment: Some(("/*", "*/")),
+            quotes: &['\''],
+        },
+        // Unknown languages still get strings, numbers and common comments coloured
+        _ => Syntax {
+            keywords: "",
+            line_comments: &["//", "#"],
+            block_comment: Some(("/*", "*/")),
+            quotes: &['"', '\''],
+        },
+    }
+}
+
+fn highlight_code(code: &str, language: &str, theme: &Theme) -> Vec<Line<'static>> {
+    let syntax = syntax_for(language);
+    let case_insensitive = language.eq_ignore_ascii_case("sql");
+    let mut in_block_comment = false;
+
+    code.lines()
+        .map(|line| {
+            let line = line.replace('\t', "    ");
+            let mut spans: Vec<Span<'static>> = Vec::new();
+            let mut rest = line.as_str();
+
+            while !rest.is_empty() {
+                // Continue a block comment from a previous line
+                if in_block_comment {
+                    let (_, end) = syntax.block_comment.unwrap_or(("", ""));
+                    let len = match rest.find(end) {
+                        Some(i) => {
+                            in_block_comment = false;
+                            i + end.len()
+                        }
+                        None => rest.len(),
+                    };
+                    spans.push(Span::styled(rest[..len].to_string(), theme.comment));
+                    rest = &rest[len..];
+                    continue;
+                }
+
+                if syntax.line_comments.iter().any(|c| rest.starts_with(c)) {
+                    spans.push(Span::styled(rest.to_string(), theme.comment));
+                    break;
+                }
+
+                if let Some((start, _)) = syntax.block_comment
+                    && rest.starts_with(start)
+                {
+                    in_block_comment = true;
+                    spans.push(Span::styled(start.to_string(), theme.comment));
+                    rest = &rest[start.len()..];
+                    continue;
+                }
+
+                let ch = rest.chars().next().unwrap_or(' ');
+
+                let len = if syntax.quotes.contains(&ch) {
+                    let len = string_len(rest, ch);
+                    spans.push(Span::styled(rest[..len].to_string(), theme.string));
+                    len
+                } else if ch.is_ascii_digit() {
+                    let len = rest
+                        .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_' || c == '.'))
+                        .unwrap_or(rest.len());
+                    spans.push(Span::styled(rest[..len].to_string(), theme.number));
+                    len
+                } else if ch.is_alphabetic() || ch == '_' {
+                    let len = rest
+                        .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+                        .unwrap_or(rest.len());
+                    let word = &rest[..len];
+                    let is_keyword = syntax.keywords.split_whitespace().any(|k| {
+                        if case_insensitive {
+                            k.eq_ignore_ascii_case(word)
+                        } else {
+                            k == word
+                        }
+                    });
+                    let style = if is_keyword {
+                        theme.keyword
+                    } else if ch.is_uppercase() {
+                        theme.type_name
+                    } else {
+                        theme.text
+                    };
+                    spans.push(Span::styled(word.to_string(), style));
+                    len
+                } else {
+                    spans.push(Span::styled(ch.to_string(), theme.text));
+                    ch.len_utf8()
+                };
+
+                rest = &rest[len..];
+            }
+
+            Line::from(spans)
+        })
+        .collect()
+}
+
+/// Byte length of the string literal at the start of `s`, up to and including
+/// the closing quote (or the end of the line if it isn't closed)
+fn string_len(s: &str, quote: char) -> usize {
+    let mut escaped = false;
+
+    for (i, c) in s.char_indices().skip(1) {
+        if escaped {
+            escaped = false;
+        } else if c == '\\' {
+            escaped = true;
+        } else if c == quote {
+            return i + c.len_utf8();
+        }
+    }
+
+    s.len()
+}
+
+/// Render `{{ bold red "text" }}` style templates.
+///
+/// Each word before the quoted text is a modifier (see `parse_modifier`), a foreground
+/// color (see `parse_color`) or a background color prefixed with `on_`.
+fn render_template(input: &str, theme: &Theme) -> Result<Vec<Line<'static>>> {
+    let mut lines = vec![Vec::new()];
+    let mut rest = input;
+
+    let push_text = |lines: &mut Vec<Vec<Span<'static>>>, text: &str, style: Style| {
+        for (i, part) in text.split('\n').enumerate() {
+            if i > 0 {
+                lines.push(Vec::new());
+            }
+            if !part.is_empty()
+                && let Some(line) = lines.last_mut()
+            {
+                line.push(Span::styled(replace_emoji(part), style));
+            }
+        }
+    };
+
+    while let Some(start) = rest.find("{{") {
+        push_text(&mut lines, &rest[..start], theme.text);
+
+        let after = &rest[start + 2..];
+        let end = after
+            .find("}}")
+            .ok_or_else(|| NibbleError::ConfigError("Unclosed '{{' in template".to_string()))?;
+
+        let (style, text) = parse_template_action(&after[..end], theme.text)?;
+        push_text(&mut lines, &text, style);
+
+        rest = &after[end + 2..];
+    }
+    push_text(&mut lines, rest, theme.text);
+
+    Ok(lines.into_iter().map(Line::from).collect())
+}
+
+fn parse_template_action(action: &str, base: Style) -> Result<(Style, String)> {
+    let mut style = base;
+    let mut texts = Vec::new();
+    let mut chars = action.trim().chars().peekable();
+
+    while let Some(&ch) = chars.peek() {
+        if ch.is_whitespace() {
+            chars.next();
+        } else if ch == '"' {
+            chars.next();
+            let mut text = String::new();
+            let mut closed = false;
+            while let Some(c) = chars.next() {
+                match c {
+                    '\\' => match chars.next() {
+                        Some('n') => text.push('\n'),
+                        Some(other) => text.push(other),
+                        None => {}
+                    },
+                    '"' => {
+                        closed = true;
+                        break;
+                    }
+                    c => text.push(c),
+                }
+            }
+            if !closed {
+                return Err(NibbleError::ConfigError(format!(
+                    "Unclosed string in template action '{}'",
+                    action.trim()
+                )));
+            }
+            texts.push(text);
+        } else {
+            let mut word = String::new();
+            while let Some(&c) = chars.peek() {
+                if c.is_whitespace() || c == '"' {
+                    break;
+                }
+                word.push(c);
+                chars.next();
+            }
+
+            style = if let Some(bg) = word.strip_prefix("on_") {
+                style.bg(parse_color(bg)?)
+            } else if let Ok(modifier) = parse_modifier(&word) {
+                style.add_modifier(modifier)
+            } else {
+                style.fg(parse_color(&word)?)
+            };
+        }
+    }
+
+    Ok((style, texts.join(" ")))
+}
+
+const EMOJI: &[(&str, &str)] = &[
+    ("+1", "👍"),
+    ("-1", "👎"),
+    ("100", "💯"),
+    ("alien", "👽"),
+    ("art", "🎨"),
+    ("beer", "🍺"),
+    ("bell", "🔔"),
+    ("book", "📖"),
+    ("books", "📚"),
+    ("boom", "💥"),
+    ("bug", "🐛"),
+    ("bulb", "💡"),
+    ("calendar", "📆"),
+    ("check", "✔️"),
+    ("clipboard", "📋"),
+    ("clock", "🕐"),
+    ("cloud", "☁️"),
+    ("coffee", "☕"),
+    ("construction", "🚧"),
+    ("crab", "🦀"),
+    ("cross_mark", "❌"),
+    ("dart", "🎯"),
+    ("exclamation", "❗"),
+    ("eyes", "👀"),
+    ("file_folder", "📁"),
+    ("fire", "🔥"),
+    ("gear", "⚙️"),
+    ("gift", "🎁"),
+    ("globe_with_meridians", "🌐"),
+    ("hammer", "🔨"),
+    ("heart", "❤️"),
+    ("heavy_check_mark", "✔️"),
+    ("hourglass", "⌛"),
+    ("information_source", "ℹ️"),
+    ("key", "🔑"),
+    ("label", "🏷️"),
+    ("laughing", "😆"),
+    ("link", "🔗"),
+    ("lock", "🔒"),
+    ("mag", "🔍"),
+    ("memo", "📝"),
+    ("moon", "🌙"),
+    ("package", "📦"),
+    ("page_facing_up", "📄"),
+    ("pencil", "📝"),
+    ("pencil2", "✏️"),
+    ("point_right", "👉"),
+    ("pushpin", "📌"),
+    ("question", "❓"),
+    ("recycle", "♻️"),
+    ("rocket", "🚀"),
+    ("rotating_light", "🚨"),
+    ("seedling", "🌱"),
+    ("shield", "🛡️"),
+    ("smile", "😄"),
+    ("sparkles", "✨"),
+    ("star", "⭐"),
+    ("stop_sign", "🛑"),
+    ("sun", "☀️"),
+    ("tada", "🎉"),
+    ("thinking", "🤔"),
+    ("thumbsdown", "👎"),
+    ("thumbsup", "👍"),
+    ("tools", "🛠️"),
+    ("trash", "🗑️"),
+    ("truck", "🚚"),
+    ("unlock", "🔓"),
+    ("warning", "⚠️"),
+    ("wave", "👋"),
+    ("white_check_mark", "✅"),
+    ("wrench", "🔧"),
+    ("x", "❌"),
+    ("zap", "⚡"),
+];
+
+/// Replace `:shortcode:` emoji with their unicode characters; unknown codes are kept
+fn replace_emoji(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(start) = rest.find(':') {
+        out.push_str(&rest[..start]);
+        let after = &rest[start + 1..];
+
+        let emoji = after.find(':').and_then(|end| {
+            let code = &after[..end];
+            EMOJI
+                .iter()
+                .find(|(name, _)| *name == code)
+                .map(|(_, emoji)| (*emoji, end))
+        });
+
+        match emoji {
+            Some((emoji, end)) => {
+                out.push_str(emoji);
+                rest = &after[end + 1..];
+            }
+            None => {
+                out.push(':');
+                rest = after;
+            }
+        }
+    }
+
+    out.push_str(rest);
+    out
+}
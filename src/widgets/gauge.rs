@@ -1,34 +1,112 @@
 use crate::{
     error::{NibbleError, Result},
-    style::StyleConfig,
-    tui,
+    style::{StyleConfig, parse_color},
+    tui::{self, forward_lines},
 };
 use clap::Args;
-use crossterm::event::{self, Event, KeyCode};
+use crossterm::event::{self, Event, KeyCode, KeyModifiers};
 use ratatui::{
     Frame,
+    layout::Rect,
     style::{Color, Style},
-    widgets::{Block, Borders, Gauge as RatatuiGauge},
+    symbols,
+    text::{Line, Span},
+    widgets::{Block, Borders, Gauge as RatatuiGauge, LineGauge},
 };
+use std::collections::VecDeque;
+use std::fs::File;
+use std::io::{IsTerminal, stdin};
+use std::path::Path;
+use std::process::{Command, ExitStatus, Stdio};
+use std::sync::mpsc::{self, Receiver, TryRecvError};
+use std::thread;
 use std::time::{Duration, Instant};
 
 #[derive(Args, Debug)]
 pub struct GaugeArgs {
-    /// Target value (0-100) - gauge will animate from 0 to this value
-    #[arg(short = 'v', long, default_value = "0")]
-    pub value: u16,
+    /// Target value (0 to --total) - gauge will animate from 0 to this value
+    #[arg(
+        short = 'v',
+        long,
+        default_value = "0",
+        conflicts_with_all = ["follow", "multi", "indeterminate", "watch_file"]
+    )]
+    pub value: String,
+
+    /// Value of a full gauge, in any unit. Accepts size suffixes like 10M or 2GiB,
+    /// which imply --bytes
+    #[arg(long)]
+    pub total: Option<String>,
+
+    /// Show values as human-readable byte sizes (KiB, MiB, ...)
+    #[arg(long)]
+    pub bytes: bool,
+
+    /// Read progress from stdin instead of animating: one value (out of --total),
+    /// 'value%', 'value/total' pair or 'value label' per line. Exits when stdin closes or 100% is reached
+    #[arg(long, conflicts_with = "multi")]
+    pub follow: bool,
+
+    /// Show one bar per job, read from '<id> <value>[/<total>] [label]' lines on stdin,
+    /// plus a bar for all of them. Exits when stdin closes
+    #[arg(long)]
+    pub multi: bool,
+
+    /// Read --follow or --multi lines from this file or named pipe instead of stdin
+    #[arg(long)]
+    pub input: Option<String>,
+
+    /// Colors of the --multi bars (comma-separated, repeated as needed)
+    #[arg(long)]
+    pub colors: Option<String>,
+
+    /// Remove --multi bars once they reach 100% instead of keeping them
+    #[arg(long)]
+    pub hide_finished: bool,
+
+    /// Show the size of this file out of --total, e.g. the destination of a copy.
+    /// Exits once it reaches --total, or when the watched process exits
+    #[arg(long, requires = "total", conflicts_with_all = ["follow", "multi"])]
+    pub watch_file: Option<String>,
+
+    /// Finish when the process with this id exits (Unix only)
+    #[arg(long, conflicts_with_all = ["follow", "multi", "command"])]
+    pub pid: Option<u32>,
+
+    /// Command to run, given after `--`. The gauge finishes when it exits and
+    /// nibble exits with its status
+    #[arg(last = true, conflicts_with_all = ["follow", "multi"])]
+    pub command: Vec<String>,
+
+    /// Show a bouncing bar for work of unknown size until a key is pressed
+    /// (or stdin closes with --follow)
+    #[arg(long, conflicts_with = "multi")]
+    pub indeterminate: bool,
+
+    /// Bar style (block, line, ascii, eighths, braille)
+    #[arg(long, default_value = "block")]
+    pub bar_style: String,
+
+    /// Color the bar with a gradient between two colors, e.g. 'red,green' or '#ff8800,#00ff88'
+    #[arg(long)]
+    pub gradient: Option<String>,
 
     /// Label text to display on the gauge
     #[arg(short, long, default_value = "")]
     pub label: String,
 
+    /// Label template with {label}, {pos}, {total}, {percent}, {rate}, {eta} and {elapsed},
+    /// e.g. '{label} {pos}/{total} {rate}/s ETA {eta}'
+    #[arg(long)]
+    pub template: Option<String>,
+
     /// Title of the gauge block
     #[arg(short, long, default_value = "")]
     pub title: String,
 
-    /// Height of the gauge in lines
-    #[arg(long, default_value = "3")]
-    pub height: u16,
+    /// Height of the gauge in lines (default: 3, or 10 with --multi)
+    #[arg(long)]
+    pub height: Option<u16>,
 
     /// How fast to update in milliseconds (time between each increment)
     #[arg(long, default_value = "50")]
@@ -42,31 +120,112 @@ pub struct GaugeArgs {
     pub style: StyleConfig,
 }
 
-pub fn run(args: GaugeArgs) -> anyhow::Result<()> {
+pub fn run(mut args: GaugeArgs) -> anyhow::Result<()> {
     // Validate args
-    if args.height == 0 {
+    let height = args.height.unwrap_or(if args.multi { 10 } else { 3 });
+    if height == 0 {
         return Err(
             NibbleError::InvalidDimensions("Height must be greater than 0".to_string()).into(),
         );
     }
 
-    if args.value > 100 {
+    let (total, byte_suffix) = match args.total.as_deref() {
+        Some(total) => parse_amount(total)?,
+        None => (100.0, false),
+    };
+    if total <= 0.0 {
         return Err(
-            NibbleError::InvalidDimensions("Value must be between 0 and 100".to_string()).into(),
+            NibbleError::InvalidDimensions("Total must be greater than 0".to_string()).into(),
         );
     }
 
+    let (value, _) = parse_amount(&args.value)?;
+    if !(0.0..=total).contains(&value) {
+        return Err(NibbleError::InvalidDimensions(format!(
+            "Value must be between 0 and {}",
+            total
+        ))
+        .into());
+    }
+
     if args.time == 0 {
         return Err(
             NibbleError::InvalidDimensions("Time must be greater than 0".to_string()).into(),
         );
     }
 
-    let mut terminal = tui::init_inline(args.height)?;
+    parse_bar_style(&args.bar_style)?;
+    parse_gradient(args.gradient.as_deref())?;
+
+    if let Some(ref colors) = args.colors {
+        colors
+            .split(',')
+            .map(|c| parse_color(c.trim()))
+            .collect::<Result<Vec<_>>>()?;
+    }
+
+    #[cfg(not(unix))]
+    if args.pid.is_some() {
+        return Err(NibbleError::ConfigError("--pid is only supported on Unix".to_string()).into());
+    }
+    if let Some(pid) = args.pid
+        && (pid == 0 || !process_running(pid))
+    {
+        return Err(NibbleError::ConfigError(format!("No process with id {}", pid)).into());
+    }
+
+    // Progress lines come from stdin or --input in --follow and --multi mode
+    let lines = if args.follow || args.multi {
+        Some(progress_lines(args.input.as_deref())?)
+    } else {
+        None
+    };
 
-    // Always animate from 0 to target value
-    let mut current_value = 0u16;
-    let target_value = args.value;
+    // Output of the command is held back until the gauge is done
+    let (out_tx, out_rx) = mpsc::channel();
+    let (err_tx, err_rx) = mpsc::channel();
+    let mut child = match args.command.split_first() {
+        Some((program, program_args)) => {
+            let mut child = Command::new(program)
+                .args(program_args)
+                .stdin(Stdio::null())
+                .stdout(Stdio::piped())
+                .stderr(Stdio::piped())
+                .spawn()
+                .map_err(|e| {
+                    NibbleError::ConfigError(format!("Failed to run '{}': {}", program, e))
+                })?;
+            if let Some(stdout) = child.stdout.take() {
+                forward_lines(stdout, out_tx);
+            }
+            if let Some(stderr) = child.stderr.take() {
+                forward_lines(stderr, err_tx);
+            }
+            Some(child)
+        }
+        None => None,
+    };
+
+    // A watched process without a file to measure has no amount to show
+    let tracks_process = args.pid.is_some() || child.is_some();
+    let watching = tracks_process || args.watch_file.is_some();
+    if tracks_process && args.watch_file.is_none() {
+        args.indeterminate = true;
+    }
+
+    let mut terminal = tui::init_inline(height)?;
+
+    // Animate from 0 to the target value in steps of 1%, or follow the input
+    let bytes = args.bytes || byte_suffix;
+    let mut progress = Progress::new(total, bytes);
+    let mut bars = Bars::new(total, bytes);
+    if args.label.is_empty() {
+        bars.aggregate.label = Some("Total".to_string());
+    }
+    let target_value = value / total * 100.0;
+    let mut closed = false;
+    let mut exited = false;
+    let mut status: Option<ExitStatus> = None;
     let update_interval = Duration::from_millis(args.time);
     let mut last_update = Instant::now();
 
@@ -74,14 +233,31 @@ pub fn run(args: GaugeArgs) -> anyhow::Result<()> {
         // Render current state
         terminal
             .draw(|frame| {
-                if let Err(e) = render(frame, &args, current_value) {
+                let result = if args.multi {
+                    render_multi(frame, &args, &bars)
+                } else {
+                    render(frame, &args, &progress)
+                };
+                if let Err(e) = result {
                     eprintln!("Render error: {}", e);
                 }
             })
             .map_err(|e| NibbleError::RenderError(e.to_string()))?;
 
+        // Followed progress ends on its own, leaving the final gauge on screen
+        let complete = !args.indeterminate && progress.percent() >= 100.0;
+        if lines.is_some() && (closed || (args.follow && complete)) {
+            break;
+        }
+
+        // Watched work ends with its process, or once the file is complete
+        if exited || (!tracks_process && args.watch_file.is_some() && complete) {
+            break;
+        }
+
         // Check if we've reached the target
-        if current_value >= target_value {
+        if lines.is_none() && !watching && !args.indeterminate && progress.percent() >= target_value
+        {
             // Wait for user input after reaching target
             if let Event::Key(key) = event::read()? {
                 match key.code {
@@ -93,67 +269,736 @@ pub fn run(args: GaugeArgs) -> anyhow::Result<()> {
             // Check for events with timeout
             if event::poll(update_interval)?
                 && let Event::Key(key) = event::read()?
-                && matches!(key.code, KeyCode::Char('q') | KeyCode::Esc)
+                && (matches!(key.code, KeyCode::Char('q') | KeyCode::Esc)
+                    || (key.code == KeyCode::Char('c')
+                        && key.modifiers.contains(KeyModifiers::CONTROL)))
             {
                 tui::restore()?;
+
+                // Stop the command like a shell on Ctrl+C would
+                if let Some(ref mut child) = child {
+                    child.kill().ok();
+                    child.wait().ok();
+                    std::process::exit(130);
+                }
                 return Ok(());
             }
 
             // Update progress if enough time has passed
             if last_update.elapsed() >= update_interval {
-                current_value = (current_value + 1).min(target_value);
+                match lines {
+                    Some(ref rx) if args.multi => closed = read_lines(rx, |l| bars.update(l)),
+                    Some(ref rx) => closed = read_lines(rx, |l| progress.update(l)),
+                    None if watching => {
+                        if let Some(ref path) = args.watch_file {
+                            progress.set(file_size(path).min(total));
+                        }
+
+                        exited = match child {
+                            Some(ref mut child) => {
+                                status = child.try_wait()?;
+                                status.is_some()
+                            }
+                            None => args.pid.is_some_and(|pid| !process_running(pid)),
+                        };
+
+                        // Leave a full gauge behind once the process is done
+                        if exited && args.watch_file.is_none() {
+                            args.indeterminate = false;
+                            progress.set(total);
+                        }
+                    }
+                    None if args.indeterminate => {}
+                    None => progress.set((progress.value + total / 100.0).min(value)),
+                }
                 last_update = Instant::now();
             }
         }
     }
 
     tui::restore()?;
+
+    // Pass on the command's output and exit code, giving the reader threads a
+    // moment to catch up with the closed pipes
+    if let Some(status) = status {
+        while let Ok(line) = out_rx.recv_timeout(Duration::from_millis(50)) {
+            println!("{}", line);
+        }
+        while let Ok(line) = err_rx.recv_timeout(Duration::from_millis(50)) {
+            eprintln!("{}", line);
+        }
+        std::process::exit(status.code().unwrap_or(1));
+    }
     Ok(())
 }
 
-fn render(frame: &mut Frame, args: &GaugeArgs, current_value: u16) -> Result<()> {
-    let area = frame.area();
+/// Size of the file at `path` in bytes, or 0 while it doesn't exist yet
+fn file_size(path: &str) -> f64 {
+    std::fs::metadata(path).map_or(0.0, |metadata| metadata.len() as f64)
+}
 
-    let gauge_style = args.style.gauge_style()?;
+/// Whether a process with this id is still alive. Signal 0 only checks that it
+/// exists; processes of other users refuse it with EPERM but are alive all the same.
+#[cfg(unix)]
+fn process_running(pid: u32) -> bool {
+    let Ok(pid) = libc::pid_t::try_from(pid) else {
+        return false;
+    };
+    // SAFETY: signal 0 is never delivered
+    let result = unsafe { libc::kill(pid, 0) };
+    result == 0 || std::io::Error::last_os_error().raw_os_error() == Some(libc::EPERM)
+}
 
-    // Get the gauge color (foreground color from style)
-    let gauge_color = if let Some(ref color_str) = args.style.fg {
-        crate::style::parse_color(color_str)?
-    } else if let Some(ref color_str) = args.style.border_color {
-        crate::style::parse_color(color_str)?
+#[cfg(not(unix))]
+fn process_running(_pid: u32) -> bool {
+    false
+}
+
+/// How far back the throughput is averaged
+const RATE_WINDOW: Duration = Duration::from_secs(5);
+
+/// Position of the gauge
+struct Progress {
+    value: f64,
+    total: f64,
+    /// Show values as byte sizes
+    bytes: bool,
+    /// Label from the latest --follow line, shown instead of --label
+    label: Option<String>,
+    started: Instant,
+    /// Recent (time, value) pairs for the throughput
+    samples: VecDeque<(Instant, f64)>,
+}
+
+impl Progress {
+    fn new(total: f64, bytes: bool) -> Self {
+        let started = Instant::now();
+        Self {
+            value: 0.0,
+            total,
+            bytes,
+            label: None,
+            started,
+            samples: VecDeque::from([(started, 0.0)]),
+        }
+    }
+
+    fn set(&mut self, value: f64) {
+        let now = Instant::now();
+        self.value = value;
+        self.samples.push_back((now, value));
+        while self.samples.len() > 2
+            && self
+                .samples
+                .front()
+                .is_some_and(|(time, _)| now.duration_since(*time) > RATE_WINDOW)
+        {
+            self.samples.pop_front();
+        }
+    }
+
+    /// Apply a --follow line
+    fn update(&mut self, line: &str) {
+        if let Some((value, total, label)) = parse_progress_line(line, self.total) {
+            self.set(value);
+            if let Some(total) = total {
+                self.total = total;
+            }
+            if label.is_some() {
+                self.label = label;
+            }
+        }
+    }
+
+    fn percent(&self) -> f64 {
+        if self.total > 0.0 {
+            (self.value / self.total * 100.0).clamp(0.0, 100.0)
+        } else {
+            // Nothing to measure yet, like the --multi aggregate before any bar arrives
+            0.0
+        }
+    }
+
+    /// Units per second over the last few seconds
+    fn rate(&self) -> Option<f64> {
+        let (first, last) = (self.samples.front()?, self.samples.back()?);
+        let seconds = last.0.duration_since(first.0).as_secs_f64();
+        (seconds > 0.0).then(|| (last.1 - first.1) / seconds)
+    }
+
+    /// Time left at the current rate
+    fn eta(&self) -> Option<Duration> {
+        let rate = self.rate().filter(|r| *r > 0.0)?;
+        let seconds = ((self.total - self.value) / rate).max(0.0);
+        Duration::try_from_secs_f64(seconds).ok()
+    }
+
+    fn format(&self, value: f64) -> String {
+        if self.bytes {
+            format_bytes(value)
+        } else {
+            format_number(value)
+        }
+    }
+
+    /// Text shown on the gauge: the --template, or the label followed by the amount
+    fn label(&self, args: &GaugeArgs) -> String {
+        let text = self.label.as_deref().unwrap_or(&args.label);
+
+        let Some(ref template) = args.template else {
+            // Without a total there is no amount to show
+            if args.indeterminate {
+                return text.to_string();
+            }
+            let amount = if args.percentage {
+                format!("{}%", self.percent().floor())
+            } else {
+                format!("{}/{}", self.format(self.value), self.format(self.total))
+            };
+            return if text.is_empty() {
+                amount
+            } else {
+                // Show current value in custom label
+                format!("{} {}", text, amount)
+            };
+        };
+
+        template
+            .replace("{label}", text)
+            .replace("{pos}", &self.format(self.value))
+            .replace("{total}", &self.format(self.total))
+            .replace("{percent}", &format!("{}%", self.percent().floor()))
+            .replace(
+                "{rate}",
+                &self.rate().map_or("--".to_string(), |r| self.format(r)),
+            )
+            .replace(
+                "{eta}",
+                &self.eta().map_or("--".to_string(), format_duration),
+            )
+            .replace("{elapsed}", &format_duration(self.started.elapsed()))
+            .trim()
+            .to_string()
+    }
+}
+
+/// Parse a number with an optional size suffix (K, KB, KiB, M, ... T). Returns
+/// the value and whether a suffix was given.
+fn parse_amount(amount: &str) -> Result<(f64, bool)> {
+    let amount = amount.trim();
+    let split = amount
+        .find(|c: char| c.is_ascii_alphabetic())
+        .unwrap_or(amount.len());
+    let (number, suffix) = amount.split_at(split);
+
+    let multiplier: f64 = match suffix.to_lowercase().as_str() {
+        "" | "b" => 1.0,
+        "k" | "kib" => 1024.0,
+        "kb" => 1e3,
+        "m" | "mib" => 1024f64.powi(2),
+        "mb" => 1e6,
+        "g" | "gib" => 1024f64.powi(3),
+        "gb" => 1e9,
+        "t" | "tib" => 1024f64.powi(4),
+        "tb" => 1e12,
+        _ => {
+            return Err(NibbleError::ConfigError(format!(
+                "Unknown size suffix '{}'. Valid suffixes: B, K, KB, KiB, M, MB, MiB, G, GB, GiB, T, TB, TiB",
+                suffix
+            )));
+        }
+    };
+
+    let number = number
+        .trim()
+        .parse::<f64>()
+        .ok()
+        .filter(|n| n.is_finite())
+        .ok_or_else(|| NibbleError::ConfigError(format!("Invalid amount: {}", amount)))?;
+
+    Ok((number * multiplier, !suffix.is_empty()))
+}
+
+/// Whole numbers without decimals, anything else with up to two
+fn format_number(value: f64) -> String {
+    let text = format!("{:.2}", value);
+    text.trim_end_matches('0').trim_end_matches('.').to_string()
+}
+
+/// Human-readable byte size, e.g. 1.5 MiB
+fn format_bytes(value: f64) -> String {
+    const UNITS: [&str; 5] = ["KiB", "MiB", "GiB", "TiB", "PiB"];
+
+    if value.abs() < 1024.0 {
+        return format!("{} B", value.round());
+    }
+
+    let mut size = value / 1024.0;
+    let mut unit = 0;
+    while size.abs() >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.1} {}", size, UNITS[unit])
+}
+
+/// Compact duration, e.g. 45s, 3m07s or 1h02m
+fn format_duration(duration: Duration) -> String {
+    let seconds = duration.as_secs();
+    if seconds >= 3600 {
+        format!("{}h{:02}m", seconds / 3600, seconds % 3600 / 60)
+    } else if seconds >= 60 {
+        format!("{}m{:02}s", seconds / 60, seconds % 60)
     } else {
-        Color::Cyan // Default gauge color
+        format!("{}s", seconds)
+    }
+}
+
+/// The bars of --multi mode, in the order their ids first appeared
+struct Bars {
+    bars: Vec<(String, Progress)>,
+    /// Sum of every bar
+    aggregate: Progress,
+    /// Total and units of new bars
+    total: f64,
+    bytes: bool,
+}
+
+impl Bars {
+    fn new(total: f64, bytes: bool) -> Self {
+        Self {
+            bars: Vec::new(),
+            aggregate: Progress::new(0.0, bytes),
+            total,
+            bytes,
+        }
+    }
+
+    /// Apply an `<id> <progress>` line, adding a bar for new ids
+    fn update(&mut self, line: &str) {
+        let Some((id, rest)) = line.trim().split_once(char::is_whitespace) else {
+            return;
+        };
+        if parse_progress_line(rest, self.total).is_none() {
+            return;
+        }
+
+        let index = match self.bars.iter().position(|(bar_id, _)| bar_id == id) {
+            Some(index) => index,
+            None => {
+                let mut bar = Progress::new(self.total, self.bytes);
+                bar.label = Some(id.to_string());
+                self.bars.push((id.to_string(), bar));
+                self.bars.len() - 1
+            }
+        };
+        self.bars[index].1.update(rest);
+
+        self.aggregate.total = self.bars.iter().map(|(_, bar)| bar.total).sum();
+        self.aggregate.set(
+            self.bars
+                .iter()
+                .map(|(_, bar)| bar.value.min(bar.total))
+                .sum(),
+        );
+    }
+}
+
+/// Forward progress lines from a file or named pipe, or from stdin
+fn progress_lines(input: Option<&str>) -> Result<Receiver<String>> {
+    let (tx, rx) = mpsc::channel();
+
+    match input {
+        Some(path) => {
+            if !Path::new(path).exists() {
+                return Err(NibbleError::ConfigError(format!(
+                    "Input '{}' does not exist",
+                    path
+                )));
+            }
+            // Opening a named pipe blocks until a writer shows up
+            let path = path.to_string();
+            thread::spawn(move || {
+                if let Ok(file) = File::open(path) {
+                    forward_lines(file, tx);
+                }
+            });
+        }
+        None => {
+            if stdin().is_terminal() {
+                return Err(NibbleError::ConfigError(
+                    "--follow and --multi need progress lines piped to stdin or --input"
+                        .to_string(),
+                ));
+            }
+            forward_lines(stdin(), tx);
+        }
+    }
+
+    Ok(rx)
+}
+
+/// Apply every line received so far. Returns true once the input has closed.
+fn read_lines(rx: &Receiver<String>, mut apply: impl FnMut(&str)) -> bool {
+    loop {
+        match rx.try_recv() {
+            Ok(line) => apply(&line),
+            Err(TryRecvError::Empty) => return false,
+            Err(TryRecvError::Disconnected) => return true,
+        }
+    }
+}
+
+/// Parse `VALUE`, `VALUE/TOTAL` or `VALUE%`, optionally followed by a label.
+/// Values may have size suffixes like --total, and percentages are of `total`.
+/// Lines that don't start with a number are ignored.
+fn parse_progress_line(line: &str, total: f64) -> Option<(f64, Option<f64>, Option<String>)> {
+    let line = line.trim();
+    let (amount, label) = match line.split_once(char::is_whitespace) {
+        Some((amount, label)) => (amount, Some(label.trim().to_string())),
+        None => (line, None),
+    };
+
+    let (amount, percent) = match amount.strip_suffix('%') {
+        Some(amount) => (amount, true),
+        None => (amount, false),
     };
+    let (value, new_total) = match amount.split_once('/') {
+        Some((value, total)) => (value, Some(parse_amount(total).ok()?.0)),
+        None => (amount, None),
+    };
+    let (mut value, _) = parse_amount(value).ok()?;
+    if percent {
+        value = value / 100.0 * new_total.unwrap_or(total);
+    }
+
+    Some((value, new_total, label.filter(|l| !l.is_empty())))
+}
+
+fn render(frame: &mut Frame, args: &GaugeArgs, progress: &Progress) -> Result<()> {
+    let mut area = frame.area();
+
+    // Add block if title or border is specified
+    if let Some(block) = gauge_block(args)? {
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+        area = inner;
+    }
+
+    render_bar(frame, area, args, progress, None)
+}
+
+/// Stack one line per bar above a line for all of them
+fn render_multi(frame: &mut Frame, args: &GaugeArgs, bars: &Bars) -> Result<()> {
+    let mut area = frame.area();
+
+    if let Some(block) = gauge_block(args)? {
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+        area = inner;
+    }
+
+    let colors = match args.colors {
+        Some(ref colors) => colors
+            .split(',')
+            .map(|c| parse_color(c.trim()).map(Some))
+            .collect::<Result<Vec<_>>>()?,
+        None => vec![None],
+    };
+
+    let shown = bars
+        .bars
+        .iter()
+        .enumerate()
+        .filter(|(_, (_, bar))| !(args.hide_finished && bar.percent() >= 100.0))
+        .take(area.height.saturating_sub(1) as usize);
+
+    for (y, (i, (_, bar))) in (area.y..).zip(shown) {
+        let row = Rect {
+            y,
+            height: 1,
+            ..area
+        };
+        render_bar(frame, row, args, bar, colors[i % colors.len()])?;
+    }
+
+    if area.height > 0 {
+        let row = Rect {
+            y: area.bottom() - 1,
+            height: 1,
+            ..area
+        };
+        render_bar(frame, row, args, &bars.aggregate, None)?;
+    }
+    Ok(())
+}
+
+/// Gauge color: the foreground color, falling back to the border color
+fn gauge_color(args: &GaugeArgs) -> Result<Color> {
+    if let Some(ref color_str) = args.style.fg {
+        parse_color(color_str)
+    } else if let Some(ref color_str) = args.style.border_color {
+        parse_color(color_str)
+    } else {
+        Ok(Color::Cyan) // Default gauge color
+    }
+}
+
+/// Draw the bar for `progress` in `color` instead of the configured style if given
+fn render_bar(
+    frame: &mut Frame,
+    area: Rect,
+    args: &GaugeArgs,
+    progress: &Progress,
+    color: Option<Color>,
+) -> Result<()> {
+    let bar_style = parse_bar_style(&args.bar_style)?;
+    let gradient = parse_gradient(args.gradient.as_deref())?;
+
+    // A gradient is drawn in its start color first, then recolored cell by cell
+    let color = gradient.map(|(start, _)| start).or(color);
+    let mut gauge_style = args.style.gauge_style()?;
+    if let Some(color) = color {
+        gauge_style = gauge_style.fg(color);
+    }
 
     // Invert text color for better visibility
     // If gauge is filled, text should be dark; if empty, text should be light
+    let gauge_color = color.map_or_else(|| gauge_color(args), Ok)?;
     let label_style = Style::default().fg(invert_color(gauge_color));
+    let empty_style = Style::default().fg(Color::DarkGray);
 
-    let label = if args.label.is_empty() {
-        if args.percentage {
-            format!("{}%", current_value)
-        } else {
-            format!("{}/100", current_value)
+    let label = progress.label(args);
+    let ratio = progress.percent() / 100.0;
+
+    match bar_style {
+        BarStyle::Block | BarStyle::Eighths if !args.indeterminate => {
+            let gauge = RatatuiGauge::default()
+                .gauge_style(gauge_style)
+                .label(Span::styled(label, label_style))
+                .ratio(ratio)
+                .use_unicode(bar_style == BarStyle::Eighths);
+            frame.render_widget(gauge, area);
+        }
+        BarStyle::Line if !args.indeterminate => {
+            let gauge = LineGauge::default()
+                .filled_symbol(symbols::line::THICK_HORIZONTAL)
+                .filled_style(gauge_style)
+                .unfilled_style(empty_style)
+                .label(label)
+                .ratio(ratio);
+            frame.render_widget(gauge, area);
+        }
+        _ => {
+            // Label on the left, like LineGauge, and the bar in the rest of the line
+            let label_width = if label.is_empty() {
+                0
+            } else {
+                Line::raw(label.as_str()).width() + 1
+            };
+            let width = (area.width as usize).saturating_sub(label_width);
+            let fill = if args.indeterminate {
+                let elapsed = progress.started.elapsed().as_millis() as u64;
+                Fill::Pulse((elapsed / args.time) as usize)
+            } else {
+                Fill::Ratio(ratio)
+            };
+
+            let mut spans = Vec::new();
+            if !label.is_empty() {
+                spans.push(Span::raw(format!("{} ", label)));
+            }
+            spans.extend(bar_spans(bar_style, width, fill, gauge_style, empty_style));
+            frame.render_widget(Line::from(spans), area);
         }
+    }
+
+    if let Some((start, end)) = gradient {
+        apply_gradient(frame, area, start, end);
+    }
+    Ok(())
+}
+
+/// Look of the bar
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum BarStyle {
+    /// ratatui's Gauge, filled with full blocks
+    Block,
+    /// ratatui's LineGauge
+    Line,
+    /// `[====>    ]`
+    Ascii,
+    /// Gauge filled with eighth blocks for smoother progress
+    Eighths,
+    /// Braille dots, eight steps per cell
+    Braille,
+}
+
+fn parse_bar_style(style: &str) -> Result<BarStyle> {
+    match style.to_lowercase().as_str() {
+        "block" => Ok(BarStyle::Block),
+        "line" => Ok(BarStyle::Line),
+        "ascii" => Ok(BarStyle::Ascii),
+        "eighths" | "unicode" => Ok(BarStyle::Eighths),
+        "braille" => Ok(BarStyle::Braille),
+        _ => Err(NibbleError::ConfigError(format!(
+            "Unknown bar style '{}'. Valid styles: block, line, ascii, eighths, braille",
+            style
+        ))),
+    }
+}
+
+/// How much of a text bar is filled
+enum Fill {
+    Ratio(f64),
+    /// Animation step of an indeterminate bar
+    Pulse(usize),
+}
+
+/// Braille characters with 1 to 7 of their 8 dots set
+const BRAILLE_STEPS: [char; 7] = ['⡀', '⡄', '⡆', '⡇', '⣇', '⣧', '⣷'];
+
+/// Spans of a bar drawn with characters, `width` columns wide
+fn bar_spans(
+    bar_style: BarStyle,
+    width: usize,
+    fill: Fill,
+    filled_style: Style,
+    empty_style: Style,
+) -> Vec<Span<'static>> {
+    let (open, close) = if bar_style == BarStyle::Ascii {
+        ("[", "]")
     } else {
-        // Show current value in custom label
-        if args.percentage {
-            format!("{} {}%", args.label, current_value)
-        } else {
-            format!("{} {}/100", args.label, current_value)
+        ("", "")
+    };
+    let inner = width.saturating_sub(open.len() + close.len());
+    let (full, empty) = match bar_style {
+        BarStyle::Block | BarStyle::Eighths => ('█', ' '),
+        BarStyle::Line => ('━', '─'),
+        BarStyle::Ascii => ('=', ' '),
+        BarStyle::Braille => ('⣿', '⠀'),
+    };
+
+    // Empty cells before the filled part, the filled part and empty cells after it
+    let (before, filled, after) = match fill {
+        Fill::Pulse(step) => {
+            // A segment a quarter of the bar wide bouncing between both ends
+            let segment = (inner / 4).clamp(1, inner.max(1)).min(inner);
+            let travel = inner - segment;
+            let period = (2 * travel).max(1);
+            let step = step % period;
+            let offset = if step <= travel { step } else { period - step };
+            (
+                offset,
+                full.to_string().repeat(segment),
+                inner - offset - segment,
+            )
+        }
+        Fill::Ratio(ratio) if bar_style == BarStyle::Braille => {
+            let dots = (ratio * inner as f64 * 8.0).round() as usize;
+            let mut filled = full.to_string().repeat(dots / 8);
+            if !dots.is_multiple_of(8) {
+                filled.push(BRAILLE_STEPS[dots % 8 - 1]);
+            }
+            let used = dots.div_ceil(8);
+            (0, filled, inner - used)
+        }
+        Fill::Ratio(ratio) => {
+            let cells = ((ratio * inner as f64).round() as usize).min(inner);
+            let mut filled = full.to_string().repeat(cells);
+            if bar_style == BarStyle::Ascii && cells > 0 && cells < inner {
+                filled.pop();
+                filled.push('>');
+            }
+            (0, filled, inner - cells)
         }
     };
 
-    let mut gauge = RatatuiGauge::default()
-        .gauge_style(gauge_style)
-        .label(ratatui::text::Span::styled(label, label_style))
-        .percent(current_value);
+    vec![
+        Span::raw(open),
+        Span::styled(empty.to_string().repeat(before), empty_style),
+        Span::styled(filled, filled_style),
+        Span::styled(empty.to_string().repeat(after), empty_style),
+        Span::raw(close),
+    ]
+}
 
-    // Add block if title or border is specified
-    if !args.title.is_empty() || args.style.border != "none" {
-        let border_type = args.style.border_type()?;
-        let border_style = args.style.border_style()?;
-        let block = Block::default()
+/// Parse `START,END` into two colors for a gradient
+fn parse_gradient(gradient: Option<&str>) -> Result<Option<(Color, Color)>> {
+    let Some(gradient) = gradient else {
+        return Ok(None);
+    };
+
+    match gradient.split_once(',') {
+        Some((start, end)) => Ok(Some((parse_color(start.trim())?, parse_color(end.trim())?))),
+        None => Err(NibbleError::ConfigError(format!(
+            "Invalid gradient '{}': expected two colors, e.g. 'red,green'",
+            gradient
+        ))),
+    }
+}
+
+/// Recolor every cell drawn in the `start` color, blending towards `end` from
+/// left to right across `area`
+fn apply_gradient(frame: &mut Frame, area: Rect, start: Color, end: Color) {
+    let (start_rgb, end_rgb) = (rgb(start), rgb(end));
+    let span = area.width.saturating_sub(1).max(1) as f64;
+    let buf = frame.buffer_mut();
+
+    for x in area.left()..area.right() {
+        let t = (x - area.x) as f64 / span;
+        let mix = |a: u8, b: u8| (a as f64 + (b as f64 - a as f64) * t).round() as u8;
+        let color = Color::Rgb(
+            mix(start_rgb.0, end_rgb.0),
+            mix(start_rgb.1, end_rgb.1),
+            mix(start_rgb.2, end_rgb.2),
+        );
+
+        for y in area.top()..area.bottom() {
+            let cell = &mut buf[(x, y)];
+            if cell.fg == start {
+                cell.fg = color;
+            }
+            if cell.bg == start {
+                cell.bg = color;
+            }
+        }
+    }
+}
+
+/// RGB value of a color, using the usual xterm palette for named colors
+fn rgb(color: Color) -> (u8, u8, u8) {
+    match color {
+        Color::Rgb(r, g, b) => (r, g, b),
+        Color::Black => (0, 0, 0),
+        Color::Red => (205, 0, 0),
+        Color::Green => (0, 205, 0),
+        Color::Yellow => (205, 205, 0),
+        Color::Blue => (0, 0, 238),
+        Color::Magenta => (205, 0, 205),
+        Color::Cyan => (0, 205, 205),
+        Color::Gray => (229, 229, 229),
+        Color::DarkGray => (127, 127, 127),
+        Color::LightRed => (255, 0, 0),
+        Color::LightGreen => (0, 255, 0),
+        Color::LightYellow => (255, 255, 0),
+        Color::LightBlue => (92, 92, 255),
+        Color::LightMagenta => (255, 0, 255),
+        Color::LightCyan => (0, 255, 255),
+        _ => (255, 255, 255),
+    }
+}
+
+/// Block around the gauge if a title or border is specified
+fn gauge_block(args: &GaugeArgs) -> Result<Option<Block<'_>>> {
+    if args.title.is_empty() && args.style.border == "none" {
+        return Ok(None);
+    }
+
+    let border_type = args.style.border_type()?;
+    let border_style = args.style.border_style()?;
+    Ok(Some(
+        Block::default()
             .title(args.title.as_str())
             .borders(if args.style.border == "none" {
                 Borders::NONE
@@ -161,12 +1006,8 @@ fn render(frame: &mut Frame, args: &GaugeArgs, current_value: u16) -> Result<()>
                 Borders::ALL
             })
             .border_type(border_type)
-            .border_style(border_style);
-        gauge = gauge.block(block);
-    }
-
-    frame.render_widget(gauge, area);
-    Ok(())
+            .border_style(border_style),
+    ))
 }
 
 /// Invert color for better contrast
@@ -0,0 +1,138 @@
+use crate::{
+    ansi,
+    error::{NibbleError, Result},
+};
+use clap::Args;
+
+#[derive(Args, Debug)]
+pub struct JoinArgs {
+    /// Text blocks to join (may contain newlines and ANSI styling)
+    #[arg(required = true)]
+    pub blocks: Vec<String>,
+
+    /// Join blocks side by side (default)
+    #[arg(long, conflicts_with = "vertical")]
+    pub horizontal: bool,
+
+    /// Stack blocks on top of each other
+    #[arg(long)]
+    pub vertical: bool,
+
+    /// Alignment: top, middle, bottom (horizontal) or left, center, right (vertical)
+    #[arg(short, long)]
+    pub align: Option<String>,
+}
+
+/// Where a shorter or narrower block is placed within the joined output
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Position {
+    Start,
+    Center,
+    End,
+}
+
+pub fn run(args: JoinArgs) -> anyhow::Result<()> {
+    let blocks: Vec<Vec<&str>> = args
+        .blocks
+        .iter()
+        .map(|b| b.trim_end_matches('\n').split('\n').collect())
+        .collect();
+
+    let lines = if args.vertical {
+        let position = parse_position(args.align.as_deref().unwrap_or("left"), true)?;
+        join_vertical(&blocks, position)
+    } else {
+        let position = parse_position(args.align.as_deref().unwrap_or("top"), false)?;
+        join_horizontal(&blocks, position)
+    };
+
+    for line in lines {
+        println!("{}", line);
+    }
+
+    Ok(())
+}
+
+fn parse_position(align: &str, vertical: bool) -> Result<Position> {
+    match (align.to_lowercase().as_str(), vertical) {
+        ("top", false) | ("left", true) => Ok(Position::Start),
+        ("middle" | "center" | "centre", _) => Ok(Position::Center),
+        ("bottom", false) | ("right", true) => Ok(Position::End),
+        (_, false) => Err(NibbleError::ConfigError(format!(
+            "Invalid alignment '{}' for --horizontal. Valid alignments: top, middle, bottom",
+            align
+        ))),
+        (_, true) => Err(NibbleError::ConfigError(format!(
+            "Invalid alignment '{}' for --vertical. Valid alignments: left, center, right",
+            align
+        ))),
+    }
+}
+
+/// Split `total` spare cells into (before, after) for the given position
+fn split_space(total: usize, position: Position) -> (usize, usize) {
+    match position {
+        Position::Start => (0, total),
+        Position::Center => (total / 2, total - total / 2),
+        Position::End => (total, 0),
+    }
+}
+
+/// Pad `line` with spaces, resetting any styling first so padding stays unstyled
+fn pad(line: &str, before: usize, after: usize) -> String {
+    let reset = if after > 0 && line.contains('\x1b') {
+        "\x1b[0m"
+    } else {
+        ""
+    };
+    format!(
+        "{}{}{}{}",
+        " ".repeat(before),
+        line,
+        reset,
+        " ".repeat(after)
+    )
+}
+
+fn join_horizontal(blocks: &[Vec<&str>], position: Position) -> Vec<String> {
+    let height = blocks.iter().map(|b| b.len()).max().unwrap_or(0);
+    let mut lines = vec![String::new(); height];
+
+    for block in blocks {
+        let width = block.iter().map(|l| ansi::width(l)).max().unwrap_or(0);
+        let (top, _) = split_space(height - block.len(), position);
+
+        for (y, line) in lines.iter_mut().enumerate() {
+            let content = y.checked_sub(top).and_then(|i| block.get(i)).copied();
+            match content {
+                Some(content) => line.push_str(&pad(content, 0, width - ansi::width(content))),
+                None => line.push_str(&" ".repeat(width)),
+            }
+        }
+    }
+
+    lines
+}
+
+fn join_vertical(blocks: &[Vec<&str>], position: Position) -> Vec<String> {
+    let width = blocks
+        .iter()
+        .flatten()
+        .map(|l| ansi::width(l))
+        .max()
+        .unwrap_or(0);
+
+    blocks
+        .iter()
+        .flat_map(|block| {
+            // Align each block as a whole so its own lines stay lined up
+            let block_width = block.iter().map(|l| ansi::width(l)).max().unwrap_or(0);
+            let (before, _) = split_space(width - block_width, position);
+
+            block.iter().map(move |line| {
+                let after = width - before - ansi::width(line);
+                pad(line, before, after)
+            })
+        })
+        .collect()
+}
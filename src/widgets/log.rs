@@ -0,0 +1,261 @@
+use crate::{
+    ansi,
+    error::{NibbleError, Result},
+    style::parse_color,
+};
+use chrono::Local;
+use clap::Args;
+use ratatui::{
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+};
+use std::fmt::Write;
+use std::io::{IsTerminal, stderr};
+
+#[derive(Args, Debug)]
+pub struct LogArgs {
+    /// Log message
+    pub message: String,
+
+    /// Structured fields as key=value pairs
+    pub fields: Vec<String>,
+
+    /// Log level (debug, info, warn, error, fatal)
+    #[arg(short, long, default_value = "info")]
+    pub level: String,
+
+    /// Minimum level to print; lower levels are suppressed
+    #[arg(long, env = "NIBBLE_LOG_LEVEL", default_value = "debug")]
+    pub min_level: String,
+
+    /// Timestamp format (rfc3339, datetime, date, time, kitchen, unix or a strftime string)
+    #[arg(short, long)]
+    pub time: Option<String>,
+
+    /// Prefix printed before the message
+    #[arg(short, long)]
+    pub prefix: Option<String>,
+
+    /// Output format (text, json, logfmt)
+    #[arg(short, long, default_value = "text")]
+    pub formatter: String,
+
+    /// Override the color of the level label
+    #[arg(long)]
+    pub level_color: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Level {
+    Debug,
+    Info,
+    Warn,
+    Error,
+    Fatal,
+}
+
+impl Level {
+    fn name(self) -> &'static str {
+        match self {
+            Level::Debug => "debug",
+            Level::Info => "info",
+            Level::Warn => "warn",
+            Level::Error => "error",
+            Level::Fatal => "fatal",
+        }
+    }
+
+    /// Default palette, one color per level
+    fn color(self) -> &'static str {
+        match self {
+            Level::Debug => "blue",
+            Level::Info => "cyan",
+            Level::Warn => "yellow",
+            Level::Error => "red",
+            Level::Fatal => "magenta",
+        }
+    }
+}
+
+fn parse_level(level: &str) -> Result<Level> {
+    match level.to_lowercase().as_str() {
+        "debug" => Ok(Level::Debug),
+        "info" => Ok(Level::Info),
+        "warn" | "warning" => Ok(Level::Warn),
+        "error" => Ok(Level::Error),
+        "fatal" => Ok(Level::Fatal),
+        _ => Err(NibbleError::ConfigError(format!(
+            "Unknown log level '{}'. Valid levels: debug, info, warn, error, fatal",
+            level
+        ))),
+    }
+}
+
+fn parse_fields(fields: &[String]) -> Result<Vec<(&str, &str)>> {
+    fields
+        .iter()
+        .map(|field| {
+            field.split_once('=').ok_or_else(|| {
+                NibbleError::ConfigError(format!(
+                    "Invalid field '{}'. Fields must be key=value pairs",
+                    field
+                ))
+            })
+        })
+        .collect()
+}
+
+fn format_time(format: &str) -> Result<String> {
+    let now = Local::now();
+
+    let pattern = match format.to_lowercase().as_str() {
+        "rfc3339" => return Ok(now.to_rfc3339_opts(chrono::SecondsFormat::Secs, false)),
+        "unix" => return Ok(now.timestamp().to_string()),
+        "datetime" => "%Y-%m-%d %H:%M:%S",
+        "date" => "%Y-%m-%d",
+        "time" => "%H:%M:%S",
+        "kitchen" => "%-I:%M%p",
+        _ => format,
+    };
+
+    // chrono reports invalid specifiers as a formatting error
+    let mut time = String::new();
+    write!(time, "{}", now.format(pattern))
+        .map_err(|_| NibbleError::ConfigError(format!("Invalid time format '{}'", format)))?;
+    Ok(time)
+}
+
+pub fn run(args: LogArgs) -> anyhow::Result<()> {
+    let level = parse_level(&args.level)?;
+    let min_level = parse_level(&args.min_level)?;
+    let fields = parse_fields(&args.fields)?;
+
+    let time = args.time.as_deref().map(format_time).transpose()?;
+
+    if level >= min_level {
+        let line = match args.formatter.to_lowercase().as_str() {
+            "text" => format_text(&args, level, time.as_deref(), &fields)?,
+            "json" => format_json(&args, level, time.as_deref(), &fields),
+            "logfmt" => format_logfmt(&args, level, time.as_deref(), &fields),
+            _ => {
+                return Err(NibbleError::ConfigError(format!(
+                    "Unknown formatter '{}'. Valid formatters: text, json, logfmt",
+                    args.formatter
+                ))
+                .into());
+            }
+        };
+
+        eprintln!("{}", line);
+    }
+
+    if level == Level::Fatal {
+        std::process::exit(1);
+    }
+
+    Ok(())
+}
+
+/// Quote a logfmt value if it contains spaces, quotes or '='
+fn quote(value: &str) -> String {
+    if value.is_empty() || value.contains([' ', '"', '=', '\t', '\n']) {
+        format!("{:?}", value)
+    } else {
+        value.to_string()
+    }
+}
+
+fn format_text(
+    args: &LogArgs,
+    level: Level,
+    time: Option<&str>,
+    fields: &[(&str, &str)],
+) -> Result<String> {
+    let color = parse_color(args.level_color.as_deref().unwrap_or(level.color()))?;
+    let dim = Style::default().fg(Color::DarkGray);
+
+    let mut spans = Vec::new();
+
+    if let Some(time) = time {
+        spans.push(Span::styled(time.to_string(), dim));
+        spans.push(Span::raw(" "));
+    }
+
+    spans.push(Span::styled(
+        format!("{:<5}", level.name().to_uppercase()),
+        Style::default().fg(color).add_modifier(Modifier::BOLD),
+    ));
+    spans.push(Span::raw(" "));
+
+    if let Some(prefix) = args.prefix.as_deref() {
+        spans.push(Span::styled(
+            format!("{}:", prefix),
+            Style::default().add_modifier(Modifier::BOLD),
+        ));
+        spans.push(Span::raw(" "));
+    }
+
+    spans.push(Span::raw(args.message.clone()));
+
+    for (key, value) in fields {
+        spans.push(Span::raw(" "));
+        spans.push(Span::styled(format!("{}=", key), dim));
+        spans.push(Span::raw(quote(value)));
+    }
+
+    let line = Line::from(spans);
+
+    // Only colour output for a terminal, and respect NO_COLOR
+    if stderr().is_terminal() && std::env::var_os("NO_COLOR").is_none() {
+        Ok(ansi::line_to_string(&line))
+    } else {
+        Ok(line.to_string())
+    }
+}
+
+fn format_json(
+    args: &LogArgs,
+    level: Level,
+    time: Option<&str>,
+    fields: &[(&str, &str)],
+) -> String {
+    let json = |s: &str| serde_json::Value::from(s).to_string();
+
+    // Built by hand to keep keys in a predictable order
+    let mut pairs = Vec::new();
+    if let Some(time) = time {
+        pairs.push(format!("\"time\":{}", json(time)));
+    }
+    pairs.push(format!("\"level\":{}", json(level.name())));
+    if let Some(prefix) = args.prefix.as_deref() {
+        pairs.push(format!("\"prefix\":{}", json(prefix)));
+    }
+    pairs.push(format!("\"msg\":{}", json(&args.message)));
+    for (key, value) in fields {
+        pairs.push(format!("{}:{}", json(key), json(value)));
+    }
+
+    format!("{{{}}}", pairs.join(","))
+}
+
+fn format_logfmt(
+    args: &LogArgs,
+    level: Level,
+    time: Option<&str>,
+    fields: &[(&str, &str)],
+) -> String {
+    let mut pairs = Vec::new();
+    if let Some(time) = time {
+        pairs.push(format!("time={}", quote(time)));
+    }
+    pairs.push(format!("level={}", level.name()));
+    if let Some(prefix) = args.prefix.as_deref() {
+        pairs.push(format!("prefix={}", quote(prefix)));
+    }
+    pairs.push(format!("msg={}", quote(&args.message)));
+    for (key, value) in fields {
+        pairs.push(format!("{}={}", key, quote(value)));
+    }
+
+    pairs.join(" ")
+}
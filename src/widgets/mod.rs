@@ -1,6 +1,16 @@
 pub mod block;
 pub mod buttons;
+pub mod choose;
 pub mod confirm;
+pub mod file;
+pub mod filter;
+pub mod format;
 pub mod gauge;
 pub mod input;
+pub mod join;
+pub mod log;
+pub mod pager;
+pub mod spin;
+pub mod style;
 pub mod table;
+pub mod write;
@@ -0,0 +1,482 @@
+use crate::{
+    error::{NibbleError, Result},
+    style::StyleConfig,
+    tui,
+};
+use clap::Args;
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEvent, KeyModifiers};
+use ratatui::{
+    Frame,
+    layout::{Constraint, Layout},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Padding, Paragraph},
+};
+use std::fs;
+use tui_input::{Input, backend::crossterm::EventHandler};
+use unicode_width::UnicodeWidthChar;
+
+#[derive(Args, Debug)]
+pub struct PagerArgs {
+    /// File to display (reads stdin if omitted or '-')
+    pub file: Option<String>,
+
+    /// Title of the pager block (defaults to the file name)
+    #[arg(short, long)]
+    pub title: Option<String>,
+
+    /// Height of the pager in lines
+    #[arg(long, default_value = "20")]
+    pub height: u16,
+
+    /// Show line numbers
+    #[arg(short = 'n', long)]
+    pub line_numbers: bool,
+
+    /// Wrap long lines instead of cutting them off
+    #[arg(short = 'w', long)]
+    pub soft_wrap: bool,
+
+    /// Padding inside the block
+    #[arg(short, long, default_value = "0")]
+    pub padding: u16,
+
+    #[command(flatten)]
+    pub style: StyleConfig,
+}
+
+/// A visual row: a slice of a logical line that fits the available width
+struct VisualRow {
+    line: usize,
+    start: usize,
+    end: usize,
+}
+
+struct Pager {
+    lines: Vec<Vec<char>>,
+    /// First visual row shown in the viewport
+    offset: usize,
+    /// Number of content rows shown at once (updated on every render)
+    page_height: usize,
+    /// Number of visual rows (updated on every render)
+    total_rows: usize,
+    /// Active search query
+    query: Option<Vec<char>>,
+    /// Search input while the user is typing after '/'
+    search_input: Option<Input>,
+    /// Logical line the next render should scroll to
+    jump_to: Option<usize>,
+    /// Logical line of the last search hit, where 'n' / 'N' continue from
+    last_match: Option<usize>,
+    /// Logical line at the top of the viewport (updated on every render)
+    top_line: usize,
+    /// Status message shown at the bottom (e.g. "Pattern not found")
+    message: Option<String>,
+}
+
+impl Pager {
+    fn new(content: &str) -> Self {
+        Self {
+            lines: content.lines().map(sanitize_line).collect(),
+            offset: 0,
+            page_height: 1,
+            total_rows: 0,
+            query: None,
+            search_input: None,
+            jump_to: None,
+            last_match: None,
+            top_line: 0,
+            message: None,
+        }
+    }
+
+    fn max_offset(&self) -> usize {
+        self.total_rows.saturating_sub(self.page_height)
+    }
+
+    fn scroll_down(&mut self, rows: usize) {
+        self.offset = (self.offset + rows).min(self.max_offset());
+    }
+
+    fn scroll_up(&mut self, rows: usize) {
+        self.offset = self.offset.saturating_sub(rows);
+    }
+
+    /// Jump to the next (or previous) line containing the query, wrapping around
+    fn find(&mut self, from_line: usize, forward: bool) {
+        let Some(query) = self.query.as_ref() else {
+            return;
+        };
+
+        let count = self.lines.len();
+        if count == 0 {
+            return;
+        }
+
+        let found = (1..=count)
+            .map(|step| {
+                if forward {
+                    (from_line + step) % count
+                } else {
+                    (from_line + count * 2 - step) % count
+                }
+            })
+            .find(|&i| !find_matches(&self.lines[i], query).is_empty());
+
+        match found {
+            Some(line) => {
+                self.jump_to = Some(line);
+                self.last_match = Some(line);
+                self.message = None;
+            }
+            None => self.message = Some("Pattern not found".to_string()),
+        }
+    }
+}
+
+pub fn run(args: PagerArgs) -> anyhow::Result<()> {
+    // Validate args
+    if args.height < 3 {
+        return Err(NibbleError::InvalidDimensions("Height must be at least 3".to_string()).into());
+    }
+
+    let content = read_content(&args)?;
+    let mut pager = Pager::new(&content);
+
+    let mut terminal = tui::init_inline(args.height)?;
+
+    let result = loop {
+        terminal
+            .draw(|frame| {
+                if let Err(e) = render(frame, &args, &mut pager) {
+                    eprintln!("Render error: {}", e);
+                }
+            })
+            .map_err(|e| NibbleError::RenderError(e.to_string()))?;
+
+        if let Event::Key(key) = event::read()?
+            && handle_key_event(key, &mut pager)
+        {
+            break Ok(());
+        }
+    };
+
+    terminal.clear()?;
+    tui::restore()?;
+    result
+}
+
+fn read_content(args: &PagerArgs) -> Result<String> {
+    match args.file.as_deref() {
+        Some(path) if path != "-" => fs::read_to_string(path)
+            .map_err(|e| NibbleError::ConfigError(format!("Failed to read file: {}", e))),
+        _ => tui::read_piped_stdin()?.ok_or_else(|| {
+            NibbleError::ConfigError(
+                "No input provided (pass a file or pipe via stdin)".to_string(),
+            )
+        }),
+    }
+}
+
+/// Expand tabs and drop ANSI escape sequences and other control characters
+fn sanitize_line(line: &str) -> Vec<char> {
+    let mut chars = Vec::with_capacity(line.len());
+    let mut iter = line.chars().peekable();
+
+    while let Some(ch) = iter.next() {
+        match ch {
+            '\t' => chars.extend("    ".chars()),
+            '\x1b' => {
+                // Skip CSI sequences like "\x1b[1;31m" up to their final byte
+                if iter.next_if_eq(&'[').is_some() {
+                    for c in iter.by_ref() {
+                        if ('@'..='~').contains(&c) {
+                            break;
+                        }
+                    }
+                }
+            }
+            c if c.is_control() => {}
+            c => chars.push(c),
+        }
+    }
+
+    chars
+}
+
+/// Find all (start, end) char ranges of `query` in `line`.
+///
+/// Matching is case-insensitive unless the query contains an uppercase letter.
+fn find_matches(line: &[char], query: &[char]) -> Vec<(usize, usize)> {
+    if query.is_empty() || query.len() > line.len() {
+        return Vec::new();
+    }
+
+    let case_sensitive = query.iter().any(|c| c.is_uppercase());
+    let eq = |a: char, b: char| {
+        if case_sensitive {
+            a == b
+        } else {
+            a.to_lowercase().eq(b.to_lowercase())
+        }
+    };
+
+    let mut matches = Vec::new();
+    let mut i = 0;
+    while i + query.len() <= line.len() {
+        if query.iter().enumerate().all(|(j, q)| eq(line[i + j], *q)) {
+            matches.push((i, i + query.len()));
+            i += query.len();
+        } else {
+            i += 1;
+        }
+    }
+
+    matches
+}
+
+/// Returns true when the pager should exit
+fn handle_key_event(key: KeyEvent, pager: &mut Pager) -> bool {
+    let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+
+    // Typing a search query
+    if let Some(input) = pager.search_input.as_mut() {
+        match key.code {
+            KeyCode::Enter => {
+                let query: Vec<char> = input.value().chars().collect();
+                pager.search_input = None;
+                if query.is_empty() {
+                    pager.query = None;
+                } else {
+                    pager.query = Some(query);
+                    // Start searching from the line at the top of the viewport
+                    let count = pager.lines.len().max(1);
+                    pager.find((pager.top_line + count - 1) % count, true);
+                }
+            }
+            KeyCode::Esc => pager.search_input = None,
+            KeyCode::Char('c') if ctrl => pager.search_input = None,
+            _ => {
+                input.handle_event(&Event::Key(key));
+            }
+        }
+        return false;
+    }
+
+    let page = pager.page_height.max(1);
+    pager.message = None;
+
+    match key.code {
+        KeyCode::Char('q') | KeyCode::Esc => return true,
+        KeyCode::Char('c') if ctrl => return true,
+
+        // Navigation
+        KeyCode::Down | KeyCode::Char('j') | KeyCode::Enter => pager.scroll_down(1),
+        KeyCode::Up | KeyCode::Char('k') => pager.scroll_up(1),
+        KeyCode::PageDown | KeyCode::Char(' ') | KeyCode::Char('f') => pager.scroll_down(page),
+        KeyCode::PageUp | KeyCode::Char('b') => pager.scroll_up(page),
+        KeyCode::Char('d') => pager.scroll_down(page / 2),
+        KeyCode::Char('u') => pager.scroll_up(page / 2),
+        KeyCode::Home | KeyCode::Char('g') => pager.offset = 0,
+        KeyCode::End | KeyCode::Char('G') => pager.offset = pager.max_offset(),
+
+        // Search
+        KeyCode::Char('/') => pager.search_input = Some(Input::default()),
+        KeyCode::Char('n') => pager.find(pager.last_match.unwrap_or(pager.top_line), true),
+        KeyCode::Char('N') => pager.find(pager.last_match.unwrap_or(pager.top_line), false),
+
+        _ => {}
+    }
+
+    false
+}
+
+/// Wrap (or cut) every logical line to `width` columns
+fn visual_rows(lines: &[Vec<char>], width: usize, soft_wrap: bool) -> Vec<VisualRow> {
+    let mut rows = Vec::new();
+
+    for (index, line) in lines.iter().enumerate() {
+        if !soft_wrap {
+            rows.push(VisualRow {
+                line: index,
+                start: 0,
+                end: line.len(),
+            });
+            continue;
+        }
+
+        let mut start = 0;
+        let mut used = 0;
+        for (i, ch) in line.iter().enumerate() {
+            let ch_width = ch.width().unwrap_or(0);
+            if used + ch_width > width && i > start {
+                rows.push(VisualRow {
+                    line: index,
+                    start,
+                    end: i,
+                });
+                start = i;
+                used = 0;
+            }
+            used += ch_width;
+        }
+
+        rows.push(VisualRow {
+            line: index,
+            start,
+            end: line.len(),
+        });
+    }
+
+    rows
+}
+
+fn render(frame: &mut Frame, args: &PagerArgs, pager: &mut Pager) -> Result<()> {
+    let area = frame.area();
+    let text_style = args.style.text_style()?;
+    let match_style = text_style.add_modifier(Modifier::REVERSED);
+    let dim_style = Style::default().fg(Color::DarkGray);
+
+    let border_type = args.style.border_type()?;
+    let border_style = args.style.border_style()?;
+
+    let title = args
+        .title
+        .clone()
+        .or_else(|| args.file.clone().filter(|f| f != "-"))
+        .unwrap_or_default();
+
+    let mut block = Block::default()
+        .title(title)
+        .borders(if args.style.border == "none" {
+            Borders::NONE
+        } else {
+            Borders::ALL
+        })
+        .border_type(border_type)
+        .border_style(border_style)
+        .padding(Padding::horizontal(args.padding));
+
+    let inner = block.inner(area);
+
+    // Reserve the last row for the search prompt or status message
+    let show_status = pager.search_input.is_some() || pager.message.is_some();
+    let chunks = Layout::vertical([
+        Constraint::Min(1),
+        Constraint::Length(if show_status { 1 } else { 0 }),
+    ])
+    .split(inner);
+    let content_area = chunks[0];
+
+    let gutter_width = if args.line_numbers {
+        pager.lines.len().max(1).to_string().len() + 1
+    } else {
+        0
+    };
+    let text_width = (content_area.width as usize)
+        .saturating_sub(gutter_width)
+        .max(1);
+
+    let rows = visual_rows(&pager.lines, text_width, args.soft_wrap);
+    pager.total_rows = rows.len();
+    pager.page_height = content_area.height as usize;
+
+    if let Some(line) = pager.jump_to.take() {
+        pager.offset = rows.iter().position(|r| r.line == line).unwrap_or(0);
+    }
+    pager.offset = pager.offset.min(pager.max_offset());
+    pager.top_line = rows.get(pager.offset).map(|r| r.line).unwrap_or(0);
+
+    let lines: Vec<Line> = rows
+        .iter()
+        .skip(pager.offset)
+        .take(pager.page_height)
+        .map(|row| {
+            let mut spans = Vec::new();
+
+            if args.line_numbers {
+                let number = if row.start == 0 {
+                    (row.line + 1).to_string()
+                } else {
+                    String::new()
+                };
+                spans.push(Span::styled(
+                    format!("{:>width$} ", number, width = gutter_width - 1),
+                    dim_style,
+                ));
+            }
+
+            let line = &pager.lines[row.line];
+            let matches = pager
+                .query
+                .as_ref()
+                .map(|q| find_matches(line, q))
+                .unwrap_or_default();
+
+            // Split the row into plain and highlighted runs
+            let mut pos = row.start;
+            for (start, end) in matches {
+                let (start, end) = (start.max(row.start), end.min(row.end));
+                if start >= end {
+                    continue;
+                }
+                if pos < start {
+                    spans.push(Span::styled(
+                        line[pos..start].iter().collect::<String>(),
+                        text_style,
+                    ));
+                }
+                spans.push(Span::styled(
+                    line[start..end].iter().collect::<String>(),
+                    match_style,
+                ));
+                pos = end;
+            }
+            if pos < row.end {
+                spans.push(Span::styled(
+                    line[pos..row.end].iter().collect::<String>(),
+                    text_style,
+                ));
+            }
+
+            Line::from(spans)
+        })
+        .collect();
+
+    // Show the scroll position in the bottom border
+    if !rows.is_empty() {
+        let first = rows[pager.offset.min(rows.len() - 1)].line + 1;
+        let last = rows[(pager.offset + pager.page_height).min(rows.len()) - 1].line + 1;
+        let percent = if pager.max_offset() == 0 {
+            100
+        } else {
+            pager.offset * 100 / pager.max_offset()
+        };
+        block = block.title_bottom(
+            Line::from(format!(
+                " {}-{} of {} ({}%) ",
+                first,
+                last,
+                pager.lines.len(),
+                percent
+            ))
+            .right_aligned(),
+        );
+    }
+
+    frame.render_widget(block, area);
+    frame.render_widget(Paragraph::new(lines), content_area);
+
+    if let Some(input) = pager.search_input.as_ref() {
+        let status = Line::from(vec![
+            Span::styled("/", text_style.add_modifier(Modifier::BOLD)),
+            Span::styled(input.value(), text_style),
+            Span::styled(" ", text_style.add_modifier(Modifier::REVERSED)),
+        ]);
+        frame.render_widget(Paragraph::new(status), chunks[1]);
+    } else if let Some(message) = pager.message.as_ref() {
+        frame.render_widget(Paragraph::new(message.as_str()).style(dim_style), chunks[1]);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,205 @@
+use crate::{
+    error::{NibbleError, Result},
+    style::StyleConfig,
+    tui::{self, forward_lines},
+};
+use clap::Args;
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyModifiers};
+use ratatui::{
+    Frame,
+    text::{Line, Span},
+    widgets::{Paragraph, Widget, Wrap},
+};
+use std::process::{Command, Stdio};
+use std::sync::mpsc::{self, Receiver};
+use std::time::{Duration, Instant};
+
+#[derive(Args, Debug)]
+pub struct SpinArgs {
+    /// Spinner type (dots, line, minidot, jump, pulse, points, globe, moon, meter, hamburger)
+    #[arg(short, long, default_value = "dots")]
+    pub spinner: String,
+
+    /// Title displayed next to the spinner
+    #[arg(short, long, default_value = "Loading...")]
+    pub title: String,
+
+    /// Stream the command's output above the spinner
+    #[arg(long)]
+    pub show_output: bool,
+
+    /// Command to run, given after `--`
+    #[arg(last = true, required = true)]
+    pub command: Vec<String>,
+
+    #[command(flatten)]
+    pub style: StyleConfig,
+}
+
+/// Animation frames and the time each frame is displayed
+struct Spinner {
+    frames: &'static [&'static str],
+    interval: Duration,
+}
+
+fn parse_spinner(spinner: &str) -> Result<Spinner> {
+    let (frames, millis): (&'static [&'static str], u64) = match spinner.to_lowercase().as_str() {
+        "dots" => (&["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"], 80),
+        "line" => (&["|", "/", "-", "\\"], 100),
+        "minidot" => (&["⠋", "⠙", "⠚", "⠞", "⠖", "⠦", "⠴", "⠲", "⠳", "⠓"], 80),
+        "jump" => (&["⢄", "⢂", "⢁", "⡁", "⡈", "⡐", "⡠"], 100),
+        "pulse" => (&["█", "▓", "▒", "░", "▒", "▓"], 120),
+        "points" => (&["∙∙∙", "●∙∙", "∙●∙", "∙∙●"], 140),
+        "globe" => (&["🌍", "🌎", "🌏"], 250),
+        "moon" => (&["🌑", "🌒", "🌓", "🌔", "🌕", "🌖", "🌗", "🌘"], 125),
+        "meter" => (&["▱▱▱", "▰▱▱", "▰▰▱", "▰▰▰", "▰▰▱", "▰▱▱", "▱▱▱"], 140),
+        "hamburger" => (&["☱", "☲", "☴", "☲"], 200),
+        _ => {
+            return Err(NibbleError::ConfigError(format!(
+                "Unknown spinner '{}'. Valid spinners: dots, line, minidot, jump, pulse, points, globe, moon, meter, hamburger",
+                spinner
+            )));
+        }
+    };
+
+    Ok(Spinner {
+        frames,
+        interval: Duration::from_millis(millis),
+    })
+}
+
+pub fn run(args: SpinArgs) -> anyhow::Result<()> {
+    let spinner = parse_spinner(&args.spinner)?;
+
+    let (program, program_args) = args
+        .command
+        .split_first()
+        .ok_or_else(|| NibbleError::ConfigError("No command provided".to_string()))?;
+
+    // Output is only captured when it is shown, so nothing piles up unread
+    let output = || {
+        if args.show_output {
+            Stdio::piped()
+        } else {
+            Stdio::null()
+        }
+    };
+    let mut child = Command::new(program)
+        .args(program_args)
+        .stdin(Stdio::null())
+        .stdout(output())
+        .stderr(output())
+        .spawn()
+        .map_err(|e| NibbleError::ConfigError(format!("Failed to run '{}': {}", program, e)))?;
+
+    // Forward both output streams line by line to the render loop
+    let (tx, rx) = mpsc::channel();
+    if let Some(stdout) = child.stdout.take() {
+        forward_lines(stdout, tx.clone());
+    }
+    if let Some(stderr) = child.stderr.take() {
+        forward_lines(stderr, tx);
+    }
+
+    let mut terminal = tui::init_inline(1)?;
+    let mut frame_index = 0;
+    let mut last_update = Instant::now();
+
+    let status = loop {
+        if args.show_output {
+            print_output(&mut terminal, &rx)?;
+        }
+
+        terminal
+            .draw(|frame| {
+                if let Err(e) = render(frame, &args, spinner.frames[frame_index]) {
+                    eprintln!("Render error: {}", e);
+                }
+            })
+            .map_err(|e| NibbleError::RenderError(e.to_string()))?;
+
+        if let Some(status) = child.try_wait()? {
+            break Some(status);
+        }
+
+        // Check for events with timeout
+        if event::poll(spinner.interval)?
+            && let Event::Key(key) = event::read()?
+            && (key.code == KeyCode::Esc
+                || (key.code == KeyCode::Char('c')
+                    && key.modifiers.contains(KeyModifiers::CONTROL)))
+        {
+            child.kill().ok();
+            child.wait().ok();
+            break None;
+        }
+
+        // Advance the animation if enough time has passed
+        if last_update.elapsed() >= spinner.interval {
+            frame_index = (frame_index + 1) % spinner.frames.len();
+            last_update = Instant::now();
+        }
+    };
+
+    // Flush whatever the command printed right before exiting, giving the
+    // reader threads a moment to catch up with the closed pipes
+    if args.show_output {
+        while let Ok(line) = rx.recv_timeout(Duration::from_millis(50)) {
+            print_line(&mut terminal, line)?;
+        }
+    }
+
+    terminal.clear()?;
+    tui::restore()?;
+
+    // Propagate the command's exit code (130 when cancelled, like a shell on Ctrl+C)
+    match status {
+        Some(status) => std::process::exit(status.code().unwrap_or(1)),
+        None => std::process::exit(130),
+    }
+}
+
+/// Print pending output lines above the inline viewport
+fn print_output(terminal: &mut tui::Tui, rx: &Receiver<String>) -> Result<()> {
+    for line in rx.try_iter() {
+        print_line(terminal, line)?;
+    }
+    Ok(())
+}
+
+fn print_line(terminal: &mut tui::Tui, line: String) -> Result<()> {
+    let width = terminal.size()?.width.max(1) as usize;
+
+    // Control characters would corrupt the buffer, so expand tabs and drop the rest
+    let line: String = line
+        .replace('\t', "    ")
+        .chars()
+        .filter(|c| !c.is_control())
+        .collect();
+    let height = Line::raw(line.as_str()).width().div_ceil(width).max(1) as u16;
+
+    terminal
+        .insert_before(height, |buf| {
+            let area = buf.area;
+            Paragraph::new(line.as_str())
+                .wrap(Wrap { trim: false })
+                .render(area, buf);
+        })
+        .map_err(|e| NibbleError::RenderError(e.to_string()))
+}
+
+fn render(frame: &mut Frame, args: &SpinArgs, spinner_frame: &str) -> Result<()> {
+    let area = frame.area();
+
+    let spinner_style = args.style.gauge_style()?;
+    let text_style = args.style.text_style()?;
+
+    let line = Line::from(vec![
+        Span::styled(spinner_frame, spinner_style),
+        Span::raw(" "),
+        Span::styled(args.title.as_str(), text_style),
+    ]);
+
+    frame.render_widget(Paragraph::new(line), area);
+    Ok(())
+}
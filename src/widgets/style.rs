@@ -0,0 +1,224 @@
+use crate::{
+    ansi,
+    error::{NibbleError, Result},
+    style::StyleConfig,
+    tui,
+};
+use clap::Args;
+use ratatui::{
+    buffer::Buffer,
+    layout::{Alignment, Rect},
+    text::Text,
+    widgets::{Block, Borders, Padding, Paragraph, Widget},
+};
+use unicode_width::UnicodeWidthStr;
+
+#[derive(Args, Debug)]
+pub struct StyleArgs {
+    /// Text to style (read from stdin if omitted); multiple arguments are joined with newlines
+    pub text: Vec<String>,
+
+    /// Title of the block
+    #[arg(short, long, default_value = "")]
+    pub title: String,
+
+    /// Text alignment (left, center, right)
+    #[arg(short, long, default_value = "left")]
+    pub align: String,
+
+    /// Padding inside the border, CSS-style: "1", "1 2" or "1 2 1 2"
+    #[arg(short, long, default_value = "0 1")]
+    pub padding: String,
+
+    /// Margin outside the border, CSS-style: "1", "1 2" or "1 2 1 2"
+    #[arg(short, long, default_value = "0")]
+    pub margin: String,
+
+    /// Width of the block including border and padding (fits the text if omitted)
+    #[arg(short, long)]
+    pub width: Option<u16>,
+
+    /// Height of the block including border and padding (fits the text if omitted)
+    #[arg(long)]
+    pub height: Option<u16>,
+
+    #[command(flatten)]
+    pub style: StyleConfig,
+}
+
+/// Space around each side of a box
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Spacing {
+    pub top: u16,
+    pub right: u16,
+    pub bottom: u16,
+    pub left: u16,
+}
+
+/// Parse CSS-style spacing: one value for all sides, two for vertical/horizontal,
+/// three for top/horizontal/bottom or four for top/right/bottom/left
+pub fn parse_spacing(spacing: &str) -> Result<Spacing> {
+    let values = spacing
+        .split(|c: char| c.is_whitespace() || c == ',')
+        .filter(|v| !v.is_empty())
+        .map(|v| {
+            v.parse::<u16>()
+                .map_err(|_| NibbleError::ConfigError(format!("Invalid spacing value: {}", v)))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let (top, right, bottom, left) = match values[..] {
+        [all] => (all, all, all, all),
+        [vertical, horizontal] => (vertical, horizontal, vertical, horizontal),
+        [top, horizontal, bottom] => (top, horizontal, bottom, horizontal),
+        [top, right, bottom, left] => (top, right, bottom, left),
+        _ => {
+            return Err(NibbleError::ConfigError(format!(
+                "Invalid spacing '{}'. Use 1, 2, 3 or 4 values",
+                spacing
+            )));
+        }
+    };
+
+    Ok(Spacing {
+        top,
+        right,
+        bottom,
+        left,
+    })
+}
+
+pub fn parse_alignment(align: &str) -> Result<Alignment> {
+    match align.to_lowercase().as_str() {
+        "left" => Ok(Alignment::Left),
+        "center" | "centre" | "middle" => Ok(Alignment::Center),
+        "right" => Ok(Alignment::Right),
+        _ => Err(NibbleError::ConfigError(format!(
+            "Unknown alignment '{}'. Valid alignments: left, center, right",
+            align
+        ))),
+    }
+}
+
+pub fn run(args: StyleArgs) -> anyhow::Result<()> {
+    let text = if args.text.is_empty() {
+        tui::read_piped_stdin()?
+            .map(|s| s.trim_end_matches('\n').to_string())
+            .unwrap_or_default()
+    } else {
+        args.text.join("\n")
+    };
+
+    for line in render(&args, &text)? {
+        println!("{}", line);
+    }
+
+    Ok(())
+}
+
+/// Render the styled block into ANSI-formatted lines
+fn render(args: &StyleArgs, text: &str) -> Result<Vec<String>> {
+    let alignment = parse_alignment(&args.align)?;
+    let padding = parse_spacing(&args.padding)?;
+    let margin = parse_spacing(&args.margin)?;
+    let text_style = args.style.text_style()?;
+
+    let has_border = args.style.border != "none";
+    let has_title = !args.title.is_empty();
+    let border_size = if has_border { 2 } else { 0 };
+    // Without a top border the title takes a row of its own
+    let title_size = if has_title && !has_border { 1 } else { 0 };
+    let frame_width = border_size + padding.left + padding.right;
+    let frame_height = border_size + title_size + padding.top + padding.bottom;
+
+    let text = text.replace('\t', "    ");
+
+    // Wrap to the requested width, or size the block to fit the text
+    let lines: Vec<String> = match args.width {
+        Some(width) => {
+            let inner = width.saturating_sub(frame_width).max(1) as usize;
+            text.lines()
+                .flat_map(|line| wrap_line(line, inner))
+                .collect()
+        }
+        None => text.lines().map(str::to_string).collect(),
+    };
+
+    let content_width = lines.iter().map(|l| l.width()).max().unwrap_or(0) as u16;
+    let title_width = args.title.width() as u16 + border_size;
+    let width = args
+        .width
+        .unwrap_or((content_width + frame_width).max(title_width))
+        .max(frame_width);
+    let height = args
+        .height
+        .unwrap_or(lines.len() as u16 + frame_height)
+        .max(frame_height);
+
+    let area = Rect::new(
+        0,
+        0,
+        margin.left + width + margin.right,
+        margin.top + height + margin.bottom,
+    );
+    let block_area = Rect::new(margin.left, margin.top, width, height);
+    let mut buf = Buffer::empty(area);
+
+    let mut block = Block::default()
+        .borders(if has_border {
+            Borders::ALL
+        } else {
+            Borders::NONE
+        })
+        .border_type(args.style.border_type()?)
+        .border_style(args.style.border_style()?)
+        .padding(Padding::new(
+            padding.left,
+            padding.right,
+            padding.top,
+            padding.bottom,
+        ));
+    if has_title {
+        block = block.title(args.title.as_str());
+    }
+
+    Paragraph::new(Text::from(lines.join("\n")))
+        .style(text_style)
+        .alignment(alignment)
+        .block(block)
+        .render(block_area, &mut buf);
+
+    Ok(ansi::buffer_to_lines(&buf))
+}
+
+/// Word-wrap a single line to `width` columns, breaking long words if needed
+pub fn wrap_line(line: &str, width: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut current = String::new();
+
+    for word in line.split(' ') {
+        let sep = if current.is_empty() { 0 } else { 1 };
+        if current.width() + sep + word.width() <= width {
+            if sep == 1 {
+                current.push(' ');
+            }
+            current.push_str(word);
+            continue;
+        }
+
+        if !current.is_empty() {
+            lines.push(std::mem::take(&mut current));
+        }
+
+        // Break words that don't fit on a line of their own
+        for ch in word.chars() {
+            if current.width() + ch.to_string().width() > width && !current.is_empty() {
+                lines.push(std::mem::take(&mut current));
+            }
+            current.push(ch);
+        }
+    }
+
+    lines.push(current);
+    lines
+}
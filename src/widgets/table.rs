@@ -1,17 +1,28 @@
 use crate::{
+    ansi,
     error::{NibbleError, Result},
-    style::StyleConfig,
+    style::{StyleConfig, parse_color, parse_modifier},
     tui,
+    widgets::style::{parse_alignment, wrap_line},
 };
 use clap::Args;
-use crossterm::event::{self, Event, KeyCode};
+use crossterm::event::{self, Event, KeyCode, KeyModifiers};
 use ratatui::{
-    Frame,
-    layout::Constraint,
-    style::Style,
-    widgets::{Block, Borders, Row, Table as RatatuiTable},
+    buffer::Buffer,
+    layout::{Alignment, Constraint, Flex, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span, Text},
+    widgets::{
+        Block, Borders, Cell, HighlightSpacing, Row, Scrollbar, ScrollbarOrientation,
+        ScrollbarState, StatefulWidget, Table as RatatuiTable, TableState, Widget,
+    },
 };
+use regex::Regex;
+use std::cmp::Ordering;
 use std::fs;
+use std::io::{IsTerminal, stdout};
+use tui_input::{Input, backend::crossterm::EventHandler};
+use unicode_width::UnicodeWidthStr;
 
 #[derive(Args, Debug)]
 pub struct TableArgs {
@@ -20,7 +31,7 @@ pub struct TableArgs {
     #[arg(short, long, conflicts_with = "file")]
     pub data: Option<String>,
 
-    /// Path to data file (CSV or JSON)
+    /// Path to data file (CSV, TSV, JSON or JSON Lines); use '-' or pipe data to read stdin
     #[arg(short, long, conflicts_with = "data")]
     pub file: Option<String>,
 
@@ -48,10 +59,104 @@ pub struct TableArgs {
     #[arg(long, default_value = ",")]
     pub col_separator: String,
 
+    /// Field delimiter for CSV files: a single character or tab, comma, semicolon, pipe
+    /// (defaults to tab for .tsv files and comma otherwise)
+    #[arg(long)]
+    pub delimiter: Option<String>,
+
+    /// Path to the array inside a JSON document, e.g. 'data.items' (dot-separated keys or indices)
+    #[arg(long)]
+    pub json_path: Option<String>,
+
+    /// Columns to show, in order (comma-separated header names or 1-based indices)
+    #[arg(short, long)]
+    pub columns: Option<String>,
+
+    /// Treat the first row as data instead of column headers
+    #[arg(long, conflicts_with = "headers")]
+    pub no_header: bool,
+
     /// Highlight header row
     #[arg(long)]
     pub highlight_header: bool,
 
+    /// Select a row interactively and print it on Enter
+    #[arg(long)]
+    pub select: bool,
+
+    /// Allow selecting several rows with Space (implies --select)
+    #[arg(long)]
+    pub multi: bool,
+
+    /// Output format for selected rows (csv, tsv, json)
+    #[arg(short, long, default_value = "csv")]
+    pub output: String,
+
+    /// Print only this column of the selected rows (header name or 1-based index)
+    #[arg(long)]
+    pub return_column: Option<String>,
+
+    /// Column alignment (left, right, center), comma-separated per column or one value for all
+    #[arg(short, long)]
+    pub align: Option<String>,
+
+    /// Minimum column widths, comma-separated per column or one value for all
+    #[arg(long)]
+    pub min_width: Option<String>,
+
+    /// Maximum column widths, comma-separated per column or one value for all
+    #[arg(long)]
+    pub max_width: Option<String>,
+
+    /// How text wider than its column is shown (truncate, wrap), per column or one value for all
+    #[arg(long)]
+    pub overflow: Option<String>,
+
+    /// Decimal places for numeric cells, comma-separated per column or one value for all
+    #[arg(long)]
+    pub decimals: Option<String>,
+
+    /// Group digits of numeric cells with thousands separators
+    #[arg(long)]
+    pub thousands: bool,
+
+    /// Style cells matching a rule, e.g. 'col=Status,eq=failed,fg=red,modifier=bold'
+    /// (conditions: eq, ne, contains, gt, ge, lt, le; styles: fg, bg, modifier)
+    #[arg(long)]
+    pub style_rule: Vec<String>,
+
+    /// Background color of every other row
+    #[arg(long)]
+    pub stripe_bg: Option<String>,
+
+    /// Sort rows by a column (header name or 1-based index), optionally suffixed with :desc
+    #[arg(long)]
+    pub sort_by: Option<String>,
+
+    /// Print the whole table to stdout instead of showing it interactively
+    /// (the default when stdout is not a terminal)
+    #[arg(long, conflicts_with_all = ["select", "multi"])]
+    pub print: bool,
+
+    /// Print the table as plain aligned text without borders
+    #[arg(long, conflicts_with_all = ["markdown", "select", "multi"])]
+    pub plain: bool,
+
+    /// Print the table as a Markdown table
+    #[arg(long, conflicts_with_all = ["select", "multi"])]
+    pub markdown: bool,
+
+    /// Edit cells interactively and print the whole table in its input format on Ctrl+S
+    #[arg(
+        long,
+        conflicts_with_all = ["select", "multi", "print", "plain", "markdown", "columns", "json_path"]
+    )]
+    pub editable: bool,
+
+    /// Only accept edited values of a column matching a regex, e.g. 'cpu=[0-9]+' (repeatable)
+    #[arg(long, requires = "editable")]
+    pub validate: Vec<String>,
+
     #[command(flatten)]
     pub style: StyleConfig,
 }
@@ -64,97 +169,1157 @@ pub fn run(args: TableArgs) -> anyhow::Result<()> {
         );
     }
 
-    if args.data.is_none() && args.file.is_none() {
-        return Err(NibbleError::ConfigError(
-            "Either --data or --file must be provided".to_string(),
-        )
-        .into());
-    }
-
     // Parse table data
-    let table_data = parse_table_data(&args)?;
+    let TableData {
+        format: data_format,
+        rows: table_data,
+        json_cells,
+    } = parse_table_data(&args)?;
 
     if table_data.is_empty() {
         return Err(NibbleError::ConfigError("Table data is empty".to_string()).into());
     }
 
+    let (header, rows) = split_header(&args, &table_data);
+    let (header, mut rows) = match args.columns.as_deref() {
+        Some(columns) => select_columns(header, rows, columns)?,
+        None => (header, rows),
+    };
+    let selecting = args.select || args.multi;
+
+    if selecting && rows.is_empty() {
+        return Err(NibbleError::ConfigError("Table has no rows to select".to_string()).into());
+    }
+
+    let num_cols = column_count(&header, &rows);
+
+    // Validate output options before taking over the terminal
+    let column = match args.return_column.as_deref() {
+        Some(column) => Some(find_column(&header, column, num_cols)?),
+        None => None,
+    };
+    let format = parse_output_format(&args.output)?;
+    let sort = match args.sort_by.as_deref() {
+        Some(sort_by) => Some(parse_sort(&header, sort_by, num_cols)?),
+        None => None,
+    };
+    let validators = args
+        .validate
+        .iter()
+        .map(|v| parse_validator(&header, v, num_cols))
+        .collect::<Result<Vec<_>>>()?;
+
+    let formats = parse_column_formats(&args, num_cols)?;
+    let display_rows: Vec<Vec<String>> = rows
+        .iter()
+        .map(|row| format_row(row, &formats, args.thousands))
+        .collect();
+
+    args.stripe_bg.as_deref().map(parse_color).transpose()?;
+    let rules = args
+        .style_rule
+        .iter()
+        .map(|rule| parse_style_rule(&header, rule, num_cols))
+        .collect::<Result<Vec<_>>>()?;
+    let cell_styles = rows
+        .iter()
+        .map(|row| cell_styles(&rules, row, num_cols))
+        .collect();
+
+    let mut display = TableDisplay {
+        natural_widths: column_widths(&header, &display_rows, &formats),
+        rows: display_rows,
+        formats,
+        cell_styles,
+        rules,
+    };
+
+    let mut view = TableView::new(rows.len(), selecting || args.editable);
+    view.editable = args.editable;
+    view.sort = sort;
+    view.refresh(&rows);
+
+    // Print instead of drawing a viewport when asked to, or when nobody can see or
+    // scroll it. Piped stdin is fine: keys are read from the terminal.
+    let unseen = !stdout().is_terminal() || !tui::tty_available();
+    let interactive = selecting || args.editable;
+    if args.print || args.plain || args.markdown || (unseen && !interactive) {
+        for line in print_table(&args, &header, &mut display, &mut view)? {
+            println!("{}", line);
+        }
+        return Ok(());
+    }
+
     let mut terminal = tui::init_inline(args.height)?;
 
     let result = loop {
         terminal
             .draw(|frame| {
-                if let Err(e) = render(frame, &args, &table_data) {
+                let area = frame.area();
+                if let Err(e) = render(
+                    area,
+                    frame.buffer_mut(),
+                    &args,
+                    &header,
+                    &display,
+                    &mut view,
+                ) {
                     eprintln!("Render error: {}", e);
                 }
             })
             .map_err(|e| NibbleError::RenderError(e.to_string()))?;
 
         if let Event::Key(key) = event::read()? {
+            let page = view.page as isize;
+            let cursor = view
+                .state
+                .selected()
+                .and_then(|c| view.visible.get(c))
+                .copied();
+
+            // The cell editor takes all input while it is open
+            if let Some(ref mut editor) = view.editor {
+                let step = match key.code {
+                    KeyCode::Esc => {
+                        view.editor = None;
+                        view.message = None;
+                        continue;
+                    }
+                    KeyCode::Enter => 0,
+                    KeyCode::Tab => 1,
+                    KeyCode::BackTab => -1,
+                    _ => {
+                        editor.handle_event(&Event::Key(key));
+                        continue;
+                    }
+                };
+
+                let value = editor.value().to_string();
+                let Some(row) = cursor else {
+                    continue;
+                };
+                if let Some(validator) = validators.iter().find(|v| v.column == view.column)
+                    && !validator.pattern.is_match(&value)
+                {
+                    view.message = Some(format!(
+                        "{} must match {}",
+                        header
+                            .get(view.column)
+                            .cloned()
+                            .unwrap_or_else(|| format!("column {}", view.column + 1)),
+                        validator.source
+                    ));
+                    continue;
+                }
+
+                if rows[row].len() <= view.column {
+                    rows[row].resize(view.column + 1, String::new());
+                }
+                rows[row][view.column] = value;
+                display.update_row(&header, row, &rows[row], args.thousands);
+
+                view.editor = None;
+                view.message = None;
+                if step != 0 {
+                    view.move_cell(step, num_cols);
+                    view.open_editor(&rows, num_cols);
+                }
+                continue;
+            }
+
+            // The filter bar takes all input while it is open
+            if view.filtering {
+                match key.code {
+                    KeyCode::Enter => view.filtering = false,
+                    KeyCode::Esc => {
+                        view.filtering = false;
+                        view.filter.reset();
+                        view.refresh(&rows);
+                    }
+                    _ => {
+                        view.filter.handle_event(&Event::Key(key));
+                        view.refresh(&rows);
+                    }
+                }
+                continue;
+            }
+
             match key.code {
-                KeyCode::Char('q') | KeyCode::Esc | KeyCode::Enter => break Ok(()),
+                // Submit the edited table
+                KeyCode::Char('s') | KeyCode::Char('d')
+                    if args.editable && key.modifiers.contains(KeyModifiers::CONTROL) =>
+                {
+                    break Some(Vec::new());
+                }
+
+                // Vertical scrolling (moves the cursor in --select mode)
+                KeyCode::Up | KeyCode::Char('k') => view.move_by(-1),
+                KeyCode::Down | KeyCode::Char('j') => view.move_by(1),
+                KeyCode::PageUp => view.move_by(-page),
+                KeyCode::PageDown => view.move_by(page),
+                KeyCode::Home | KeyCode::Char('g') => view.move_by(-(rows.len() as isize)),
+                KeyCode::End | KeyCode::Char('G') => view.move_by(rows.len() as isize),
+
+                // Sorting: a column number toggles that column, 's' cycles through all of them
+                KeyCode::Char(c @ '1'..='9') => {
+                    let column = c as usize - '1' as usize;
+                    if column < num_cols {
+                        view.sort = match view.sort {
+                            Some(sort) if sort.column == column && !sort.descending => Some(Sort {
+                                column,
+                                descending: true,
+                            }),
+                            Some(sort) if sort.column == column => None,
+                            _ => Some(Sort {
+                                column,
+                                descending: false,
+                            }),
+                        };
+                        view.refresh(&rows);
+                    }
+                }
+                KeyCode::Char('s') => {
+                    view.sort = match view.sort {
+                        None => Some(Sort {
+                            column: 0,
+                            descending: false,
+                        }),
+                        Some(Sort {
+                            column,
+                            descending: false,
+                        }) => Some(Sort {
+                            column,
+                            descending: true,
+                        }),
+                        Some(Sort { column, .. }) if column + 1 < num_cols => Some(Sort {
+                            column: column + 1,
+                            descending: false,
+                        }),
+                        Some(_) => None,
+                    };
+                    view.refresh(&rows);
+                }
+
+                // Filtering
+                KeyCode::Char('/') => view.filtering = true,
+
+                // Cell cursor in --editable mode
+                KeyCode::Left | KeyCode::Char('h') if args.editable => {
+                    view.column = view.column.saturating_sub(1)
+                }
+                KeyCode::Right | KeyCode::Char('l') if args.editable => {
+                    view.column = (view.column + 1).min(num_cols.saturating_sub(1))
+                }
+                KeyCode::Tab if args.editable => view.move_cell(1, num_cols),
+                KeyCode::BackTab if args.editable => view.move_cell(-1, num_cols),
+                KeyCode::Enter if args.editable => view.open_editor(&rows, num_cols),
+
+                // Horizontal scrolling by whole columns
+                KeyCode::Left | KeyCode::Char('h') => {
+                    view.col_offset = view.col_offset.saturating_sub(1)
+                }
+                KeyCode::Right | KeyCode::Char('l') => {
+                    view.col_offset = (view.col_offset + 1).min(view.max_col_offset)
+                }
+
+                // Toggle selection
+                KeyCode::Char(' ') | KeyCode::Tab | KeyCode::Char('x') if args.multi => {
+                    if let Some(cursor) = cursor {
+                        view.selected[cursor] = !view.selected[cursor];
+                    }
+                }
+                KeyCode::Char('a') if args.multi => {
+                    let all = view.visible.iter().all(|&i| view.selected[i]);
+                    for &i in &view.visible {
+                        view.selected[i] = !all;
+                    }
+                }
+
+                // Submit current selection, in the order rows are shown
+                KeyCode::Enter if selecting => {
+                    let chosen: Vec<&Vec<String>> = if view.selected.iter().any(|s| *s) {
+                        view.order
+                            .iter()
+                            .filter(|&&i| view.selected[i])
+                            .map(|&i| &rows[i])
+                            .collect()
+                    } else if let Some(cursor) = cursor {
+                        vec![&rows[cursor]]
+                    } else {
+                        continue;
+                    };
+                    break Some(chosen);
+                }
+                KeyCode::Enter => break Some(Vec::new()),
+
+                // Cancel
+                KeyCode::Esc | KeyCode::Char('q') if interactive => break None,
+                KeyCode::Esc | KeyCode::Char('q') => break Some(Vec::new()),
+                KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => break None,
+
                 _ => {}
             }
         }
     };
 
+    if !interactive {
+        tui::restore()?;
+        return match result {
+            Some(_) => Ok(()),
+            None => std::process::exit(1),
+        };
+    }
+
+    // Clear and restore terminal before printing the selection
+    terminal.clear()?;
     tui::restore()?;
-    result
+
+    match result {
+        Some(_) if args.editable => {
+            for line in format_table(&args, data_format, json_cells.as_ref(), &header, &rows)? {
+                println!("{}", line);
+            }
+            Ok(())
+        }
+        Some(chosen) => {
+            for line in format_selection(&header, &chosen, column, format, args.multi) {
+                println!("{}", line);
+            }
+            Ok(())
+        }
+        None => std::process::exit(1),
+    }
+}
+
+/// Scroll position, cursor, sorting, filtering and selection of the interactive table
+struct TableView {
+    state: TableState,
+    /// Selection flags, indexed by row
+    selected: Vec<bool>,
+    /// Row indices in sorted order
+    order: Vec<usize>,
+    /// Row indices that are shown: `order` without rows hidden by the filter
+    visible: Vec<usize>,
+    selecting: bool,
+    sort: Option<Sort>,
+    filter: Input,
+    /// Whether the filter bar is being edited
+    filtering: bool,
+    /// First visible column when the table is wider than the terminal
+    col_offset: usize,
+    max_col_offset: usize,
+    /// Number of rows visible at once, updated on every render
+    page: usize,
+    /// Height of each visible row, updated on every render
+    row_heights: Vec<usize>,
+    /// Whether the table is being printed rather than scrolled
+    printing: bool,
+    editable: bool,
+    /// Column of the cell cursor in --editable mode
+    column: usize,
+    /// Editor for the cell under the cursor, while it is open
+    editor: Option<Input>,
+    /// Validation error shown below the table
+    message: Option<String>,
+}
+
+/// Column to sort rows by
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Sort {
+    column: usize,
+    descending: bool,
 }
 
-fn parse_table_data(args: &TableArgs) -> Result<Vec<Vec<String>>> {
-    if let Some(ref file_path) = args.file {
-        parse_file(file_path)
-    } else if let Some(ref data) = args.data {
-        parse_inline_data(data, &args.row_separator, &args.col_separator)
+impl TableView {
+    fn new(row_count: usize, selecting: bool) -> Self {
+        Self {
+            state: TableState::default(),
+            selected: vec![false; row_count],
+            order: (0..row_count).collect(),
+            visible: (0..row_count).collect(),
+            selecting,
+            sort: None,
+            filter: Input::default(),
+            filtering: false,
+            col_offset: 0,
+            max_col_offset: 0,
+            page: 1,
+            row_heights: Vec::new(),
+            printing: false,
+            editable: false,
+            column: 0,
+            editor: None,
+            message: None,
+        }
+    }
+
+    /// Re-apply sorting and filtering, moving back to the first row
+    fn refresh(&mut self, rows: &[Vec<String>]) {
+        self.order = (0..rows.len()).collect();
+        if let Some(sort) = self.sort {
+            let cell = |i: usize| rows[i].get(sort.column).map_or("", |c| c.as_str());
+            self.order.sort_by(|&a, &b| {
+                let ordering = compare_cells(cell(a), cell(b));
+                if sort.descending {
+                    ordering.reverse()
+                } else {
+                    ordering
+                }
+            });
+        }
+
+        let query = self.filter.value().to_lowercase();
+        self.visible = self
+            .order
+            .iter()
+            .copied()
+            .filter(|&i| {
+                query.is_empty()
+                    || rows[i]
+                        .iter()
+                        .any(|cell| cell.to_lowercase().contains(&query))
+            })
+            .collect();
+
+        *self.state.offset_mut() = 0;
+        if self.selecting {
+            self.state.select(if self.visible.is_empty() {
+                None
+            } else {
+                Some(0)
+            });
+        }
+    }
+
+    /// Move the cursor in select mode, otherwise scroll the rows
+    fn move_by(&mut self, delta: isize) {
+        if self.visible.is_empty() {
+            return;
+        }
+
+        if self.selecting {
+            let cursor = self.state.selected().unwrap_or(0);
+            let last = self.visible.len() - 1;
+            let cursor = cursor.saturating_add_signed(delta).min(last);
+            self.state.select(Some(cursor));
+        } else {
+            let offset = self.state.offset().saturating_add_signed(delta);
+            *self.state.offset_mut() = offset.min(self.max_offset());
+        }
+    }
+
+    fn max_offset(&self) -> usize {
+        self.visible.len().saturating_sub(self.page)
+    }
+
+    /// Move the cell cursor to the next or previous cell, continuing on the
+    /// neighbouring row at the end of a row
+    fn move_cell(&mut self, delta: isize, num_cols: usize) {
+        let Some(cursor) = self.state.selected() else {
+            return;
+        };
+        if num_cols == 0 {
+            return;
+        }
+        let last = (self.visible.len() * num_cols).saturating_sub(1);
+        let cell = (cursor * num_cols + self.column)
+            .saturating_add_signed(delta)
+            .min(last);
+        self.state.select(Some(cell / num_cols));
+        self.column = cell % num_cols;
+    }
+
+    /// Start editing the cell under the cursor
+    fn open_editor(&mut self, rows: &[Vec<String>], num_cols: usize) {
+        let Some(&row) = self.state.selected().and_then(|c| self.visible.get(c)) else {
+            return;
+        };
+        if num_cols == 0 {
+            return;
+        }
+        let value = rows[row].get(self.column).cloned().unwrap_or_default();
+        self.editor = Some(Input::new(value));
+    }
+
+    /// Keep the cursor on screen and the offset within bounds
+    fn clamp(&mut self) {
+        let mut offset = self.state.offset().min(self.max_offset());
+        if let Some(cursor) = self.state.selected() {
+            if cursor < offset {
+                offset = cursor;
+            } else if cursor >= offset + self.page {
+                offset = cursor + 1 - self.page;
+            }
+        }
+        *self.state.offset_mut() = offset;
+        self.col_offset = self.col_offset.min(self.max_col_offset);
+    }
+}
+
+/// Parse `COL[:asc|:desc]`, where COL is a header name or 1-based index
+fn parse_sort(header: &[String], sort_by: &str, num_cols: usize) -> Result<Sort> {
+    // Only a known direction is a suffix, so header names may contain ':'
+    let (column, descending) = match sort_by.rsplit_once(':') {
+        Some((column, direction)) if direction.eq_ignore_ascii_case("asc") => (column, false),
+        Some((column, direction)) if direction.eq_ignore_ascii_case("desc") => (column, true),
+        _ => (sort_by, false),
+    };
+
+    Ok(Sort {
+        column: find_column(header, column, num_cols)?,
+        descending,
+    })
+}
+
+/// Compare two cells, numerically when both are numbers and otherwise with
+/// digit runs compared by value so "host-9" sorts before "host-10"
+fn compare_cells(a: &str, b: &str) -> Ordering {
+    let number = |s: &str| s.trim().replace(',', "").parse::<f64>().ok();
+
+    match (number(a), number(b)) {
+        (Some(x), Some(y)) => x.partial_cmp(&y).unwrap_or(Ordering::Equal),
+        // Numbers sort before text
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => natural_cmp(a, b),
+    }
+}
+
+fn natural_cmp(a: &str, b: &str) -> Ordering {
+    let mut a = a.chars().peekable();
+    let mut b = b.chars().peekable();
+
+    loop {
+        match (a.peek().copied(), b.peek().copied()) {
+            (None, None) => return Ordering::Equal,
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(x), Some(y)) if x.is_ascii_digit() && y.is_ascii_digit() => {
+                let take_digits = |chars: &mut std::iter::Peekable<std::str::Chars>| {
+                    let mut digits = String::new();
+                    while let Some(c) = chars.next_if(|c| c.is_ascii_digit()) {
+                        digits.push(c);
+                    }
+                    digits
+                };
+                let x = take_digits(&mut a);
+                let y = take_digits(&mut b);
+                let (x, y) = (x.trim_start_matches('0'), y.trim_start_matches('0'));
+                let ordering = x.len().cmp(&y.len()).then_with(|| x.cmp(y));
+                if ordering != Ordering::Equal {
+                    return ordering;
+                }
+            }
+            (Some(x), Some(y)) => {
+                let ordering = x.to_lowercase().cmp(y.to_lowercase());
+                if ordering != Ordering::Equal {
+                    return ordering;
+                }
+                a.next();
+                b.next();
+            }
+        }
+    }
+}
+
+/// How selected rows are written to stdout
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum OutputFormat {
+    Csv,
+    Tsv,
+    Json,
+}
+
+fn parse_output_format(format: &str) -> Result<OutputFormat> {
+    match format.to_lowercase().as_str() {
+        "csv" => Ok(OutputFormat::Csv),
+        "tsv" => Ok(OutputFormat::Tsv),
+        "json" => Ok(OutputFormat::Json),
+        _ => Err(NibbleError::ConfigError(format!(
+            "Unknown output format '{}'. Valid formats: csv, tsv, json",
+            format
+        ))),
+    }
+}
+
+/// Number of columns in the widest row
+fn column_count(header: &[String], rows: &[Vec<String>]) -> usize {
+    rows.iter()
+        .map(|row| row.len())
+        .chain([header.len()])
+        .max()
+        .unwrap_or(0)
+}
+
+/// Find a column by header name (case-insensitive) or 1-based index
+fn find_column(header: &[String], column: &str, num_cols: usize) -> Result<usize> {
+    if let Some(index) = header.iter().position(|h| h.eq_ignore_ascii_case(column)) {
+        return Ok(index);
+    }
+
+    match column.parse::<usize>() {
+        Ok(index) if (1..=num_cols).contains(&index) => Ok(index - 1),
+        Ok(index) if index >= 1 => Err(NibbleError::ConfigError(format!(
+            "Column {} is out of range: the table has {} columns",
+            index, num_cols
+        ))),
+        _ => Err(NibbleError::ConfigError(format!(
+            "Unknown column '{}'. Use a header name or a 1-based index",
+            column
+        ))),
+    }
+}
+
+fn format_selection(
+    header: &[String],
+    chosen: &[&Vec<String>],
+    column: Option<usize>,
+    format: OutputFormat,
+    multi: bool,
+) -> Vec<String> {
+    if let Some(column) = column {
+        return chosen
+            .iter()
+            .map(|row| row.get(column).cloned().unwrap_or_default())
+            .collect();
+    }
+
+    match format {
+        OutputFormat::Csv => chosen
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|c| csv_field(c, ','))
+                    .collect::<Vec<_>>()
+                    .join(",")
+            })
+            .collect(),
+        OutputFormat::Tsv => chosen
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|c| c.replace(['\t', '\n'], " "))
+                    .collect::<Vec<_>>()
+                    .join("\t")
+            })
+            .collect(),
+        OutputFormat::Json => {
+            let objects: Vec<serde_json::Value> =
+                chosen.iter().map(|row| row_to_json(header, row)).collect();
+
+            // A single selection prints an object, --multi always prints an array
+            let json = if multi {
+                serde_json::Value::Array(objects)
+            } else {
+                objects.into_iter().next().unwrap_or_default()
+            };
+            vec![json.to_string()]
+        }
+    }
+}
+
+/// Quote a CSV field if it contains the delimiter, a quote or a newline
+fn csv_field(field: &str, delimiter: char) -> String {
+    if field.contains([delimiter, '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
     } else {
-        Err(NibbleError::ConfigError(
-            "No data source provided".to_string(),
+        field.to_string()
+    }
+}
+
+/// Convert a row into a JSON object keyed by header, or an array if there is no header
+fn row_to_json(header: &[String], row: &[String]) -> serde_json::Value {
+    if header.is_empty() {
+        return serde_json::Value::from(row.to_vec());
+    }
+
+    let object = row
+        .iter()
+        .enumerate()
+        .map(|(i, cell)| {
+            let key = header
+                .get(i)
+                .filter(|h| !h.is_empty())
+                .cloned()
+                .unwrap_or_else(|| format!("column{}", i + 1));
+            (key, serde_json::Value::from(cell.as_str()))
+        })
+        .collect();
+    serde_json::Value::Object(object)
+}
+
+/// Write the whole table back out in the format it was read in. JSON cells that
+/// weren't edited keep their original value, edited ones take the type they had.
+fn format_table(
+    args: &TableArgs,
+    format: Option<DataFormat>,
+    json_cells: Option<&JsonCells>,
+    header: &[String],
+    rows: &[Vec<String>],
+) -> Result<Vec<String>> {
+    // A header given with --headers was never part of the data
+    let header_in_data = args.headers.is_none() && !header.is_empty();
+    let mut lines: Vec<&[String]> = Vec::new();
+    if header_in_data {
+        lines.push(header);
+    }
+    lines.extend(rows.iter().map(Vec::as_slice));
+
+    let Some(json_cells) = json_cells else {
+        let output = match format {
+            Some(DataFormat::Delimited(delimiter)) => lines
+                .iter()
+                .map(|row| {
+                    row.iter()
+                        .map(|c| csv_field(c, delimiter))
+                        .collect::<Vec<_>>()
+                        .join(&delimiter.to_string())
+                })
+                .collect(),
+            _ => vec![
+                lines
+                    .iter()
+                    .map(|row| row.join(&args.col_separator))
+                    .collect::<Vec<_>>()
+                    .join(&args.row_separator),
+            ],
+        };
+        return Ok(output);
+    };
+
+    // The lines are the rows of the input, so they line up with the JSON cells
+    let mut values: Vec<serde_json::Value> = lines
+        .iter()
+        .zip(&json_cells.rows)
+        .map(|(line, originals)| {
+            let cells = line
+                .iter()
+                .enumerate()
+                .map(|(i, cell)| json_value(cell, originals.get(i).and_then(Option::as_ref)));
+
+            if json_cells.objects && !header.is_empty() {
+                let flat = header
+                    .iter()
+                    .zip(cells)
+                    .filter_map(|(key, value)| Some((key.clone(), value?)))
+                    .collect();
+                serde_json::Value::Object(nest_object(flat))
+            } else {
+                serde_json::Value::Array(cells.flatten().collect())
+            }
+        })
+        .collect();
+
+    // Objects are keyed by the header row instead of repeating it
+    if json_cells.objects && header_in_data {
+        values.remove(0);
+    }
+
+    let output = match format {
+        Some(DataFormat::JsonLines) => values.iter().map(|v| v.to_string()).collect(),
+        _ => {
+            let json = serde_json::to_string_pretty(&serde_json::Value::Array(values))
+                .map_err(|e| NibbleError::RenderError(e.to_string()))?;
+            vec![json]
+        }
+    };
+    Ok(output)
+}
+
+/// JSON value to write for a cell showing `cell`, where the input had `original`
+/// (`None` for a key an object didn't have)
+fn json_value(cell: &str, original: Option<&serde_json::Value>) -> Option<serde_json::Value> {
+    match original {
+        Some(value) if json_cell(value) == cell => Some(value.clone()),
+        Some(serde_json::Value::String(_)) => Some(serde_json::Value::String(cell.to_string())),
+        None if cell.is_empty() => None,
+        // Numbers, booleans, null, arrays and objects stay JSON values where possible
+        _ => match serde_json::from_str(cell) {
+            Ok(serde_json::Value::String(_)) | Err(_) => {
+                Some(serde_json::Value::String(cell.to_string()))
+            }
+            Ok(parsed) => Some(parsed),
+        },
+    }
+}
+
+/// Turn dotted keys back into nested objects, e.g. "owner.login" -> {"owner": {"login": ..}}
+fn nest_object(
+    flat: serde_json::Map<String, serde_json::Value>,
+) -> serde_json::Map<String, serde_json::Value> {
+    let mut object = serde_json::Map::new();
+    for (key, value) in flat {
+        let mut parts: Vec<&str> = key.split('.').collect();
+        let last = parts.pop().unwrap_or_default();
+
+        let mut target = &mut object;
+        for part in parts {
+            let entry = target
+                .entry(part)
+                .or_insert_with(|| serde_json::Value::Object(serde_json::Map::new()));
+            if !entry.is_object() {
+                *entry = serde_json::Value::Object(serde_json::Map::new());
+            }
+            let serde_json::Value::Object(nested) = entry else {
+                unreachable!()
+            };
+            target = nested;
+        }
+        target.insert(last.to_string(), value);
+    }
+    object
+}
+
+/// A --validate option: edited values of `column` must match `pattern`
+struct Validator {
+    column: usize,
+    pattern: Regex,
+    /// The regex as given, for error messages
+    source: String,
+}
+
+/// Parse `COL=REGEX`. The regex has to match the whole value.
+fn parse_validator(header: &[String], validate: &str, num_cols: usize) -> Result<Validator> {
+    let (column, source) = validate.split_once('=').ok_or_else(|| {
+        NibbleError::ConfigError(format!(
+            "Invalid validation '{}': expected COL=REGEX",
+            validate
         ))
+    })?;
+
+    let pattern = Regex::new(&format!("^(?:{})$", source)).map_err(|e| {
+        NibbleError::ConfigError(format!("Invalid regex for column '{}': {}", column, e))
+    })?;
+
+    Ok(Validator {
+        column: find_column(header, column.trim(), num_cols)?,
+        pattern,
+        source: source.to_string(),
+    })
+}
+
+/// Separate the header row from the data rows
+fn split_header(args: &TableArgs, data: &[Vec<String>]) -> (Vec<String>, Vec<Vec<String>>) {
+    if let Some(ref custom_headers) = args.headers {
+        let headers: Vec<String> = custom_headers
+            .split(',')
+            .map(|h| h.trim().to_string())
+            .collect();
+        (headers, data.to_vec())
+    } else if data.len() > 1 && !args.no_header {
+        // Use first row as headers
+        (data[0].clone(), data[1..].to_vec())
+    } else {
+        // No separate headers
+        (vec![], data.to_vec())
     }
 }
 
-fn parse_file(path: &str) -> Result<Vec<Vec<String>>> {
-    let content = fs::read_to_string(path)
-        .map_err(|e| NibbleError::ConfigError(format!("Failed to read file: {}", e)))?;
+/// Rows of the table as read from --data, a file or stdin
+struct TableData {
+    /// Format of file or stdin data (`None` for --data)
+    format: Option<DataFormat>,
+    rows: Vec<Vec<String>>,
+    /// The JSON values behind the cells of JSON input
+    json_cells: Option<JsonCells>,
+}
+
+fn parse_table_data(args: &TableArgs) -> Result<TableData> {
+    if let Some(ref data) = args.data {
+        return Ok(TableData {
+            format: None,
+            rows: parse_inline_data(data, &args.row_separator, &args.col_separator)?,
+            json_cells: None,
+        });
+    }
+
+    let (path, content) = match args.file.as_deref() {
+        Some("-") | None => {
+            let content = tui::read_piped_stdin()?.ok_or_else(|| {
+                NibbleError::ConfigError(
+                    "Either --data or --file must be provided, or data piped to stdin".to_string(),
+                )
+            })?;
+            (None, content)
+        }
+        Some(path) => {
+            let content = fs::read_to_string(path)
+                .map_err(|e| NibbleError::ConfigError(format!("Failed to read file: {}", e)))?;
+            (Some(path), content)
+        }
+    };
+
+    // Spreadsheet exports often start with a UTF-8 byte order mark
+    let content = content.strip_prefix('\u{feff}').unwrap_or(&content);
+
+    let format = detect_format(path, content, args.delimiter.as_deref())?;
+    let json_cells = match format {
+        DataFormat::Json => parse_json(content, args.json_path.as_deref())?,
+        DataFormat::JsonLines => parse_json_lines(content)?,
+        DataFormat::Delimited(delimiter) => {
+            return Ok(TableData {
+                format: Some(format),
+                rows: parse_csv(content, delimiter)?,
+                json_cells: None,
+            });
+        }
+    };
+    Ok(TableData {
+        format: Some(format),
+        rows: json_cells.text(),
+        json_cells: Some(json_cells),
+    })
+}
+
+/// Format of file or stdin data
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum DataFormat {
+    Json,
+    JsonLines,
+    Delimited(char),
+}
+
+/// Pick the data format from the file extension, falling back to sniffing the content
+fn detect_format(path: Option<&str>, content: &str, delimiter: Option<&str>) -> Result<DataFormat> {
+    let extension = path
+        .and_then(|p| std::path::Path::new(p).extension())
+        .and_then(|e| e.to_str())
+        .map(str::to_lowercase);
+
+    match extension.as_deref() {
+        Some("json") => return Ok(DataFormat::Json),
+        Some("jsonl" | "ndjson") => return Ok(DataFormat::JsonLines),
+        Some("csv") => {
+            return Ok(DataFormat::Delimited(parse_delimiter(
+                delimiter.unwrap_or("comma"),
+            )?));
+        }
+        Some("tsv") => {
+            return Ok(DataFormat::Delimited(parse_delimiter(
+                delimiter.unwrap_or("tab"),
+            )?));
+        }
+        _ => {}
+    }
+
+    if let Some(delimiter) = delimiter {
+        return Ok(DataFormat::Delimited(parse_delimiter(delimiter)?));
+    }
 
-    if path.ends_with(".json") {
-        parse_json(&content)
-    } else if path.ends_with(".csv") {
-        parse_csv(&content)
+    let trimmed = content.trim_start();
+    if trimmed.starts_with('[') {
+        return Ok(DataFormat::Json);
+    }
+    if trimmed.starts_with('{') {
+        // A single object spanning several lines is JSON, one object per line is JSON Lines
+        let single = serde_json::from_str::<serde_json::Value>(trimmed).is_ok();
+        return Ok(if single && trimmed.trim_end().lines().count() > 1 {
+            DataFormat::Json
+        } else {
+            DataFormat::JsonLines
+        });
+    }
+
+    // Tab-separated if the first line has more tabs than commas
+    let first = trimmed.lines().next().unwrap_or_default();
+    if first.matches('\t').count() > first.matches(',').count() {
+        Ok(DataFormat::Delimited('\t'))
     } else {
-        // Try CSV by default
-        parse_csv(&content)
+        Ok(DataFormat::Delimited(','))
     }
 }
 
-fn parse_csv(content: &str) -> Result<Vec<Vec<String>>> {
+fn parse_delimiter(delimiter: &str) -> Result<char> {
+    match delimiter.to_lowercase().as_str() {
+        "comma" => Ok(','),
+        "tab" | "\\t" => Ok('\t'),
+        "semicolon" => Ok(';'),
+        "pipe" => Ok('|'),
+        _ => {
+            let mut chars = delimiter.chars();
+            match (chars.next(), chars.next()) {
+                (Some(c), None) if c != '"' && c != '\n' && c != '\r' => Ok(c),
+                _ => Err(NibbleError::ConfigError(format!(
+                    "Invalid delimiter '{}'. Use a single character or tab, comma, semicolon, pipe",
+                    delimiter
+                ))),
+            }
+        }
+    }
+}
+
+/// Parse RFC 4180 CSV: quoted fields may contain delimiters, newlines and
+/// doubled quotes. Fields are kept as written, blank lines are skipped and
+/// short rows are padded to the width of the widest row.
+fn parse_csv(content: &str, delimiter: char) -> Result<Vec<Vec<String>>> {
+    let error = |line: usize, message: &str| {
+        NibbleError::ConfigError(format!("CSV parse error on line {}: {}", line, message))
+    };
+
     let mut rows = Vec::new();
+    let mut row = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut in_quotes = false;
+    let mut after_quote = false;
+    let mut line = 1;
+    let mut quote_line = 1;
 
-    for line in content.lines() {
-        if line.trim().is_empty() {
+    let mut chars = content.chars().peekable();
+    while let Some(ch) = chars.next() {
+        if in_quotes {
+            match ch {
+                '"' if chars.next_if_eq(&'"').is_some() => field.push('"'),
+                '"' => {
+                    in_quotes = false;
+                    after_quote = true;
+                }
+                _ => {
+                    if ch == '\n' {
+                        line += 1;
+                    }
+                    field.push(ch);
+                }
+            }
             continue;
         }
 
-        let row: Vec<String> = line
-            .split(',')
-            .map(|cell| cell.trim().to_string())
+        match ch {
+            c if c == delimiter || c == '\n' => {
+                // Spaces are part of unquoted fields (RFC 4180)
+                row.push(std::mem::take(&mut field));
+
+                if c == '\n' {
+                    // Skip blank lines
+                    if row.len() > 1 || !row[0].trim().is_empty() || quoted {
+                        rows.push(std::mem::take(&mut row));
+                    } else {
+                        row.clear();
+                    }
+                    line += 1;
+                }
+
+                quoted = false;
+                after_quote = false;
+            }
+            '\r' if chars.peek() == Some(&'\n') => {}
+            '"' if !quoted && field.trim().is_empty() => {
+                field.clear();
+                quoted = true;
+                in_quotes = true;
+                quote_line = line;
+            }
+            '"' => return Err(error(line, "unexpected quote in unquoted field")),
+            c if after_quote => {
+                if !c.is_whitespace() {
+                    return Err(error(
+                        line,
+                        &format!("unexpected character '{}' after closing quote", c),
+                    ));
+                }
+            }
+            c => field.push(c),
+        }
+    }
+
+    if in_quotes {
+        return Err(error(quote_line, "quoted field is never closed"));
+    }
+
+    // Last row without a trailing newline
+    if !field.trim().is_empty() || quoted || !row.is_empty() {
+        row.push(field);
+        rows.push(row);
+    }
+
+    if rows.is_empty() {
+        return Err(NibbleError::ConfigError("CSV file is empty".to_string()));
+    }
+
+    // Pad ragged rows so every row has the same number of cells
+    let num_cols = rows.iter().map(|r| r.len()).max().unwrap_or(0);
+    for row in &mut rows {
+        row.resize(num_cols, String::new());
+    }
+
+    Ok(rows)
+}
+
+/// Cells of JSON input as JSON values, `None` where an object lacks a key.
+/// For arrays of objects the first row holds the keys.
+struct JsonCells {
+    /// Whether the rows were objects rather than arrays
+    objects: bool,
+    rows: Vec<Vec<Option<serde_json::Value>>>,
+}
+
+impl JsonCells {
+    /// Text of every cell as shown in the table
+    fn text(&self) -> Vec<Vec<String>> {
+        self.rows
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|value| value.as_ref().map(json_cell).unwrap_or_default())
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+fn parse_json(content: &str, json_path: Option<&str>) -> Result<JsonCells> {
+    // Parse JSON array of objects or array of arrays
+    let mut json: serde_json::Value = serde_json::from_str(content)
+        .map_err(|e| NibbleError::ConfigError(format!("Invalid JSON: {}", e)))?;
+
+    if let Some(path) = json_path {
+        json = select_json_path(json, path)?;
+    } else if let serde_json::Value::Object(ref obj) = json {
+        // Point at the arrays a wrapping object contains
+        let arrays: Vec<&str> = obj
+            .iter()
+            .filter(|(_, v)| v.is_array())
+            .map(|(k, _)| k.as_str())
             .collect();
-
-        rows.push(row);
+        if !arrays.is_empty() {
+            return Err(NibbleError::ConfigError(format!(
+                "JSON root is an object; select an array with --json-path (found: {})",
+                arrays.join(", ")
+            )));
+        }
     }
 
-    if rows.is_empty() {
-        return Err(NibbleError::ConfigError("CSV file is empty".to_string()));
+    parse_json_value(json)
+}
+
+/// Follow a dot-separated path of object keys and array indices
+fn select_json_path(json: serde_json::Value, path: &str) -> Result<serde_json::Value> {
+    let mut current = json;
+
+    for key in path
+        .trim_start_matches('.')
+        .split('.')
+        .filter(|k| !k.is_empty())
+    {
+        let next = match current {
+            serde_json::Value::Object(mut obj) => obj.swap_remove(key),
+            serde_json::Value::Array(mut arr) => key
+                .parse::<usize>()
+                .ok()
+                .filter(|&i| i < arr.len())
+                .map(|i| arr.swap_remove(i)),
+            _ => None,
+        };
+
+        current = next.ok_or_else(|| {
+            NibbleError::ConfigError(format!("JSON path '{}' not found at '{}'", path, key))
+        })?;
     }
 
-    Ok(rows)
+    Ok(current)
 }
 
-fn parse_json(content: &str) -> Result<Vec<Vec<String>>> {
-    // Parse JSON array of objects or array of arrays
-    let json: serde_json::Value = serde_json::from_str(content)
-        .map_err(|e| NibbleError::ConfigError(format!("Invalid JSON: {}", e)))?;
-
+fn parse_json_value(json: serde_json::Value) -> Result<JsonCells> {
     match json {
         serde_json::Value::Array(arr) => {
             if arr.is_empty() {
@@ -165,10 +1330,16 @@ fn parse_json(content: &str) -> Result<Vec<Vec<String>>> {
             if let Some(first) = arr.first() {
                 if first.is_array() {
                     // Array of arrays
-                    parse_json_array_of_arrays(&arr)
+                    Ok(JsonCells {
+                        objects: false,
+                        rows: parse_json_array_of_arrays(&arr),
+                    })
                 } else if first.is_object() {
                     // Array of objects
-                    parse_json_array_of_objects(&arr)
+                    Ok(JsonCells {
+                        objects: true,
+                        rows: parse_json_array_of_objects(&arr),
+                    })
                 } else {
                     Err(NibbleError::ConfigError(
                         "JSON must be array of arrays or array of objects".to_string(),
@@ -184,59 +1355,137 @@ fn parse_json(content: &str) -> Result<Vec<Vec<String>>> {
     }
 }
 
-fn parse_json_array_of_arrays(arr: &[serde_json::Value]) -> Result<Vec<Vec<String>>> {
+/// Parse JSON Lines: one JSON object or array per non-empty line
+fn parse_json_lines(content: &str) -> Result<JsonCells> {
+    let values = content
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(i, line)| {
+            serde_json::from_str(line).map_err(|e| {
+                NibbleError::ConfigError(format!("Invalid JSON on line {}: {}", i + 1, e))
+            })
+        })
+        .collect::<Result<Vec<serde_json::Value>>>()?;
+
+    parse_json_value(serde_json::Value::Array(values))
+}
+
+/// Text shown for a JSON value in a cell
+fn json_cell(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::String(s) => s.clone(),
+        serde_json::Value::Number(n) => n.to_string(),
+        serde_json::Value::Bool(b) => b.to_string(),
+        serde_json::Value::Null => "null".to_string(),
+        _ => value.to_string(),
+    }
+}
+
+fn parse_json_array_of_arrays(arr: &[serde_json::Value]) -> Vec<Vec<Option<serde_json::Value>>> {
     let mut rows = Vec::new();
 
     for item in arr {
         if let serde_json::Value::Array(row_arr) = item {
-            let row: Vec<String> = row_arr
-                .iter()
-                .map(|v| match v {
-                    serde_json::Value::String(s) => s.clone(),
-                    serde_json::Value::Number(n) => n.to_string(),
-                    serde_json::Value::Bool(b) => b.to_string(),
-                    serde_json::Value::Null => "null".to_string(),
-                    _ => v.to_string(),
-                })
-                .collect();
-            rows.push(row);
+            rows.push(row_arr.iter().cloned().map(Some).collect());
         }
     }
 
-    Ok(rows)
+    rows
 }
 
-fn parse_json_array_of_objects(arr: &[serde_json::Value]) -> Result<Vec<Vec<String>>> {
-    let mut rows = Vec::new();
+/// Flatten nested objects into dotted keys, e.g. {"owner": {"login": "x"}} -> "owner.login"
+fn flatten_object(
+    prefix: &str,
+    obj: &serde_json::Map<String, serde_json::Value>,
+    out: &mut Vec<(String, serde_json::Value)>,
+) {
+    for (key, value) in obj {
+        let key = if prefix.is_empty() {
+            key.clone()
+        } else {
+            format!("{}.{}", prefix, key)
+        };
+
+        match value {
+            serde_json::Value::Object(nested) if !nested.is_empty() => {
+                flatten_object(&key, nested, out)
+            }
+            _ => out.push((key, value.clone())),
+        }
+    }
+}
 
-    // Extract headers from first object
-    if let Some(serde_json::Value::Object(first_obj)) = arr.first() {
-        let headers: Vec<String> = first_obj.keys().cloned().collect();
-        rows.push(headers.clone());
+fn parse_json_array_of_objects(arr: &[serde_json::Value]) -> Vec<Vec<Option<serde_json::Value>>> {
+    let objects: Vec<Vec<(String, serde_json::Value)>> = arr
+        .iter()
+        .filter_map(|item| item.as_object())
+        .map(|obj| {
+            let mut fields = Vec::new();
+            flatten_object("", obj, &mut fields);
+            fields
+        })
+        .collect();
 
-        // Extract values for each object
-        for item in arr {
-            if let serde_json::Value::Object(obj) = item {
-                let row: Vec<String> = headers
-                    .iter()
-                    .map(|key| {
-                        obj.get(key)
-                            .map(|v| match v {
-                                serde_json::Value::String(s) => s.clone(),
-                                serde_json::Value::Number(n) => n.to_string(),
-                                serde_json::Value::Bool(b) => b.to_string(),
-                                serde_json::Value::Null => "null".to_string(),
-                                _ => v.to_string(),
-                            })
-                            .unwrap_or_default()
-                    })
-                    .collect();
-                rows.push(row);
+    // Union of keys across all objects, in the order they are first seen
+    let mut headers: Vec<String> = Vec::new();
+    for fields in &objects {
+        for (key, _) in fields {
+            if !headers.contains(key) {
+                headers.push(key.clone());
             }
         }
     }
 
-    Ok(rows)
+    let mut rows = vec![
+        headers
+            .iter()
+            .map(|key| Some(serde_json::Value::from(key.as_str())))
+            .collect(),
+    ];
+    for fields in objects {
+        let row = headers
+            .iter()
+            .map(|key| {
+                fields
+                    .iter()
+                    .find(|(k, _)| k == key)
+                    .map(|(_, v)| v.clone())
+            })
+            .collect();
+        rows.push(row);
+    }
+
+    rows
+}
+
+/// Keep only the listed columns, in the listed order
+fn select_columns(
+    header: Vec<String>,
+    rows: Vec<Vec<String>>,
+    columns: &str,
+) -> Result<(Vec<String>, Vec<Vec<String>>)> {
+    let num_cols = column_count(&header, &rows);
+    let indices = columns
+        .split(',')
+        .map(str::trim)
+        .filter(|c| !c.is_empty())
+        .map(|c| find_column(&header, c, num_cols))
+        .collect::<Result<Vec<_>>>()?;
+
+    let pick = |row: &[String]| -> Vec<String> {
+        indices
+            .iter()
+            .map(|&i| row.get(i).cloned().unwrap_or_default())
+            .collect()
+    };
+
+    let header = if header.is_empty() {
+        header
+    } else {
+        pick(&header)
+    };
+    Ok((header, rows.iter().map(|r| pick(r)).collect()))
 }
 
 fn parse_inline_data(data: &str, row_sep: &str, col_sep: &str) -> Result<Vec<Vec<String>>> {
@@ -262,83 +1511,863 @@ fn parse_inline_data(data: &str, row_sep: &str, col_sep: &str) -> Result<Vec<Vec
     Ok(rows)
 }
 
-fn render(frame: &mut Frame, args: &TableArgs, data: &[Vec<String>]) -> Result<()> {
-    let area = frame.area();
+/// Table contents prepared for display
+struct TableDisplay {
+    /// Rows with number formatting applied
+    rows: Vec<Vec<String>>,
+    formats: Vec<ColumnFormat>,
+    natural_widths: Vec<u16>,
+    /// Style from the matching --style-rule options for every cell
+    cell_styles: Vec<Vec<Style>>,
+    rules: Vec<StyleRule>,
+}
 
-    if data.is_empty() {
-        return Ok(());
+impl TableDisplay {
+    /// Re-format a row after one of its cells was edited
+    fn update_row(&mut self, header: &[String], index: usize, row: &[String], thousands: bool) {
+        let num_cols = self.formats.len();
+        self.rows[index] = format_row(row, &self.formats, thousands);
+        self.cell_styles[index] = cell_styles(&self.rules, row, num_cols);
+        self.natural_widths = column_widths(header, &self.rows, &self.formats);
     }
+}
 
-    // Determine number of columns
-    let num_cols = data.iter().map(|row| row.len()).max().unwrap_or(0);
+/// A --style-rule: cells in `column` (or any column) that meet every condition get `style`
+#[derive(Debug, Clone)]
+struct StyleRule {
+    column: Option<usize>,
+    conditions: Vec<Condition>,
+    style: Style,
+}
 
-    // Parse column widths or use equal distribution
-    let widths = if let Some(ref width_str) = args.widths {
-        parse_widths(width_str, num_cols)?
-    } else {
-        // Equal distribution
-        vec![Constraint::Percentage((100 / num_cols as u16).max(1)); num_cols]
+#[derive(Debug, Clone)]
+enum Condition {
+    Eq(String),
+    Ne(String),
+    Contains(String),
+    Gt(f64),
+    Ge(f64),
+    Lt(f64),
+    Le(f64),
+}
+
+impl Condition {
+    fn matches(&self, cell: &str) -> bool {
+        let number = || cell.trim().replace(',', "").parse::<f64>().ok();
+        match self {
+            Condition::Eq(value) => cell.trim().eq_ignore_ascii_case(value),
+            Condition::Ne(value) => !cell.trim().eq_ignore_ascii_case(value),
+            Condition::Contains(value) => cell.to_lowercase().contains(&value.to_lowercase()),
+            Condition::Gt(value) => number().is_some_and(|n| n > *value),
+            Condition::Ge(value) => number().is_some_and(|n| n >= *value),
+            Condition::Lt(value) => number().is_some_and(|n| n < *value),
+            Condition::Le(value) => number().is_some_and(|n| n <= *value),
+        }
+    }
+}
+
+fn parse_style_rule(header: &[String], rule: &str, num_cols: usize) -> Result<StyleRule> {
+    let error = |message: String| {
+        NibbleError::ConfigError(format!("Invalid style rule '{}': {}", rule, message))
     };
 
-    // Separate headers and rows
-    let (header_data, row_data) = if let Some(ref custom_headers) = args.headers {
-        let headers: Vec<String> = custom_headers
-            .split(',')
-            .map(|h| h.trim().to_string())
-            .collect();
-        (headers, data.to_vec())
-    } else if data.len() > 1 {
-        // Use first row as headers
-        (data[0].clone(), data[1..].to_vec())
+    let mut column = None;
+    let mut conditions = Vec::new();
+    let mut style = Style::default();
+
+    for part in rule.split(',').map(str::trim).filter(|p| !p.is_empty()) {
+        let (key, value) = part
+            .split_once('=')
+            .ok_or_else(|| error(format!("expected key=value, got '{}'", part)))?;
+        let number = || {
+            value
+                .trim()
+                .parse::<f64>()
+                .map_err(|_| error(format!("'{}' is not a number", value)))
+        };
+
+        match key.trim().to_lowercase().as_str() {
+            "col" | "column" => column = Some(find_column(header, value, num_cols)?),
+            "eq" => conditions.push(Condition::Eq(value.to_string())),
+            "ne" => conditions.push(Condition::Ne(value.to_string())),
+            "contains" => conditions.push(Condition::Contains(value.to_string())),
+            "gt" => conditions.push(Condition::Gt(number()?)),
+            "ge" | "gte" => conditions.push(Condition::Ge(number()?)),
+            "lt" => conditions.push(Condition::Lt(number()?)),
+            "le" | "lte" => conditions.push(Condition::Le(number()?)),
+            "fg" => style = style.fg(parse_color(value)?),
+            "bg" => style = style.bg(parse_color(value)?),
+            "modifier" => style = style.add_modifier(parse_modifier(value)?),
+            other => return Err(error(format!("unknown key '{}'", other))),
+        }
+    }
+
+    Ok(StyleRule {
+        column,
+        conditions,
+        style,
+    })
+}
+
+/// Combine the styles of every rule matching each cell of a row, later rules winning
+fn cell_styles(rules: &[StyleRule], row: &[String], num_cols: usize) -> Vec<Style> {
+    (0..num_cols)
+        .map(|i| {
+            let cell = row.get(i).map_or("", |c| c.as_str());
+            rules
+                .iter()
+                .filter(|rule| rule.column.is_none_or(|c| c == i))
+                .filter(|rule| rule.conditions.iter().all(|c| c.matches(cell)))
+                .fold(Style::default(), |style, rule| style.patch(rule.style))
+        })
+        .collect()
+}
+
+/// Per-column display options
+#[derive(Debug, Clone)]
+struct ColumnFormat {
+    alignment: Alignment,
+    min_width: u16,
+    max_width: Option<u16>,
+    wrap: bool,
+    decimals: Option<usize>,
+}
+
+/// Split a per-column option list. A single value applies to every column and
+/// empty entries fall back to the default.
+fn per_column(list: Option<&str>, num_cols: usize) -> Vec<Option<&str>> {
+    let values: Vec<&str> = list
+        .map(|l| l.split(',').map(str::trim).collect())
+        .unwrap_or_default();
+
+    (0..num_cols)
+        .map(|i| {
+            let value = if values.len() == 1 {
+                values[0]
+            } else {
+                values.get(i).copied().unwrap_or_default()
+            };
+            (!value.is_empty()).then_some(value)
+        })
+        .collect()
+}
+
+fn parse_column_formats(args: &TableArgs, num_cols: usize) -> Result<Vec<ColumnFormat>> {
+    let number = |value: &str, option: &str| {
+        value
+            .parse::<u16>()
+            .map_err(|_| NibbleError::ConfigError(format!("Invalid {} value: {}", option, value)))
+    };
+
+    let align = per_column(args.align.as_deref(), num_cols);
+    let min_width = per_column(args.min_width.as_deref(), num_cols);
+    let max_width = per_column(args.max_width.as_deref(), num_cols);
+    let overflow = per_column(args.overflow.as_deref(), num_cols);
+    let decimals = per_column(args.decimals.as_deref(), num_cols);
+
+    (0..num_cols)
+        .map(|i| {
+            let wrap = match overflow[i].map(str::to_lowercase).as_deref() {
+                None | Some("truncate") => false,
+                Some("wrap") => true,
+                Some(other) => {
+                    return Err(NibbleError::ConfigError(format!(
+                        "Unknown overflow '{}'. Valid values: truncate, wrap",
+                        other
+                    )));
+                }
+            };
+
+            Ok(ColumnFormat {
+                alignment: align[i].map_or(Ok(Alignment::Left), parse_alignment)?,
+                min_width: min_width[i].map_or(Ok(0), |w| number(w, "--min-width"))?,
+                max_width: max_width[i]
+                    .map(|w| number(w, "--max-width"))
+                    .transpose()?
+                    .map(|w| w.max(1)),
+                wrap,
+                decimals: decimals[i]
+                    .map(|d| number(d, "--decimals"))
+                    .transpose()?
+                    .map(usize::from),
+            })
+        })
+        .collect()
+}
+
+/// Apply number formatting to the numeric cells of a row
+fn format_row(row: &[String], formats: &[ColumnFormat], thousands: bool) -> Vec<String> {
+    row.iter()
+        .zip(formats)
+        .map(|(cell, format)| {
+            if format.decimals.is_none() && !thousands {
+                return cell.clone();
+            }
+            match cell.trim().parse::<f64>() {
+                Ok(value) if value.is_finite() => {
+                    format_number(cell.trim(), value, format.decimals, thousands)
+                }
+                _ => cell.clone(),
+            }
+        })
+        .collect()
+}
+
+/// Format a number with fixed decimals and/or thousands separators. Without
+/// `decimals` the original digits after the decimal point are kept.
+fn format_number(raw: &str, value: f64, decimals: Option<usize>, thousands: bool) -> String {
+    let text = match decimals {
+        Some(decimals) => format!("{:.*}", decimals, value),
+        None => raw.to_string(),
+    };
+
+    if !thousands {
+        return text;
+    }
+
+    let (sign, unsigned) = match text.strip_prefix(['-', '+']) {
+        Some(rest) => (&text[..1], rest),
+        None => ("", text.as_str()),
+    };
+    let (integer, fraction) = match unsigned.find(['.', 'e', 'E']) {
+        Some(i) => unsigned.split_at(i),
+        None => (unsigned, ""),
+    };
+
+    let mut grouped = String::new();
+    for (i, ch) in integer.chars().enumerate() {
+        if i > 0 && (integer.len() - i) % 3 == 0 {
+            grouped.push(',');
+        }
+        grouped.push(ch);
+    }
+
+    format!("{}{}{}", sign, grouped, fraction)
+}
+
+/// Display width of the widest cell in each column, header included, limited
+/// by the column's min/max width
+fn column_widths(header: &[String], rows: &[Vec<String>], formats: &[ColumnFormat]) -> Vec<u16> {
+    formats
+        .iter()
+        .enumerate()
+        .map(|(i, format)| {
+            // Leave room for the sort arrow after the header
+            let header_width = header.get(i).map_or(0, |h| h.width() + 2);
+            // Measure the text the way `cell_text` lays it out
+            let content = rows
+                .iter()
+                .filter_map(|row| row.get(i))
+                .map(|cell| {
+                    if format.wrap {
+                        cell.lines().map(|line| line.width()).max().unwrap_or(0)
+                    } else {
+                        cell.replace('\n', " ").width()
+                    }
+                })
+                .max()
+                .unwrap_or(0)
+                .max(header_width)
+                .clamp(1, u16::MAX as usize) as u16;
+
+            let content = content.max(format.min_width);
+            format.max_width.map_or(content, |max| content.min(max))
+        })
+        .collect()
+}
+
+/// Shorten `text` to `width` columns, ending with an ellipsis if anything was cut
+fn truncate(text: &str, width: usize) -> String {
+    if text.width() <= width {
+        return text.to_string();
+    }
+
+    let mut out = String::new();
+    let mut used = 0;
+    for ch in text.chars() {
+        let w = ch.to_string().width();
+        if used + w + 1 > width {
+            break;
+        }
+        out.push(ch);
+        used += w;
+    }
+    if width > 0 {
+        out.push('…');
+    }
+    out
+}
+
+/// Lay out a cell's text for a column: wrapped onto several lines or truncated to one
+fn cell_text(text: &str, width: u16, format: &ColumnFormat) -> Text<'static> {
+    let width = width as usize;
+    let lines: Vec<Line> = if format.wrap {
+        text.lines()
+            .flat_map(|line| wrap_line(line, width.max(1)))
+            .map(Line::from)
+            .collect()
     } else {
-        // No separate headers
-        (vec![], data.to_vec())
+        vec![Line::from(truncate(&text.replace('\n', " "), width))]
     };
 
+    Text::from(lines).alignment(format.alignment)
+}
+
+/// Pick the visible columns and their widths for the available space.
+///
+/// Returns the column range to draw and its constraints, and updates the
+/// view's horizontal scroll limit when the columns don't fit.
+fn layout_columns(
+    args: &TableArgs,
+    natural: &[u16],
+    available: u16,
+    view: &mut TableView,
+) -> Result<(std::ops::Range<usize>, Vec<Constraint>)> {
+    let num_cols = natural.len();
+    view.max_col_offset = 0;
+
+    // Explicit percentages always fit
+    if let Some(ref width_str) = args.widths {
+        return Ok((0..num_cols, parse_widths(width_str, num_cols)?));
+    }
+
+    // Sum as usize: many wide columns can add up to more than u16::MAX
+    let available = available as usize;
+    let total = natural.iter().map(|&w| w as usize).sum::<usize>() + num_cols - 1;
+    if total <= available {
+        let widths = natural.iter().map(|&w| Constraint::Length(w)).collect();
+        return Ok((0..num_cols, widths));
+    }
+
+    // Too wide: scroll horizontally, stopping once the last column is fully visible
+    let mut used = 0;
+    let mut trailing = 0;
+    for &w in natural.iter().rev() {
+        used += w as usize + 1;
+        if used > available + 1 {
+            break;
+        }
+        trailing += 1;
+    }
+    view.max_col_offset = num_cols - trailing.max(1);
+    view.col_offset = view.col_offset.min(view.max_col_offset);
+
+    let mut used = 0;
+    let mut end = view.col_offset;
+    while end < num_cols && (end == view.col_offset || used + natural[end] as usize <= available) {
+        used += natural[end] as usize + 1;
+        end += 1;
+    }
+
+    let widths = natural[view.col_offset..end]
+        .iter()
+        .map(|&w| Constraint::Length(w.min(available as u16)))
+        .collect();
+    Ok((view.col_offset..end, widths))
+}
+
+fn render(
+    area: Rect,
+    buf: &mut Buffer,
+    args: &TableArgs,
+    header_data: &[String],
+    display: &TableDisplay,
+    view: &mut TableView,
+) -> Result<()> {
+    let row_data = &display.rows;
+    let natural_widths = &display.natural_widths;
+    let formats = &display.formats;
+
+    let mut area = area;
+
+    if natural_widths.is_empty() {
+        return Ok(());
+    }
+
     let text_style = args.style.text_style()?;
 
+    // Validation errors and the filter bar go below the table
+    let bar_area = Rect {
+        y: area.bottom().saturating_sub(1),
+        height: 1.min(area.height),
+        ..area
+    };
+    if let Some(ref message) = view.message {
+        area.height = area.height.saturating_sub(1);
+        Line::styled(message.as_str(), text_style.fg(Color::Red)).render(bar_area, buf);
+    } else if view.filtering || !view.filter.value().is_empty() {
+        area.height = area.height.saturating_sub(1);
+
+        let mut spans = vec![
+            Span::styled("/", text_style.add_modifier(Modifier::BOLD)),
+            Span::styled(view.filter.value(), text_style),
+        ];
+        if view.filtering {
+            spans.push(Span::styled(
+                " ",
+                text_style.add_modifier(Modifier::REVERSED),
+            ));
+        }
+        Line::from(spans).render(bar_area, buf);
+    }
+
+    let has_block = !args.title.is_empty() || args.style.border != "none";
+    let has_border = args.style.border != "none";
+    let border_size = if has_border { 2 } else { 0 };
+    let highlight_width = if view.selecting { 2 } else { 0 };
+
+    // Rows visible below the header
+    let header_height = if header_data.is_empty() { 0 } else { 1 };
+    let title_height = if has_block && !has_border { 1 } else { 0 };
+    let rows_height = area
+        .height
+        .saturating_sub(border_size + header_height + title_height)
+        .max(1) as usize;
+
+    let available = area
+        .width
+        .saturating_sub(border_size + highlight_width)
+        .max(1);
+    // Scroll sideways to keep the cell cursor in view
+    if view.editable {
+        view.col_offset = view.col_offset.min(view.column);
+    }
+    let (mut columns, mut widths) = layout_columns(args, natural_widths, available, view)?;
+    while view.editable && view.column >= columns.end && view.col_offset < view.max_col_offset {
+        view.col_offset += 1;
+        (columns, widths) = layout_columns(args, natural_widths, available, view)?;
+    }
+
+    // Resolve the constraints the same way the table does, to truncate and wrap cells
+    let column_widths: Vec<u16> = Layout::horizontal(widths.clone())
+        .flex(Flex::Start)
+        .spacing(1)
+        .split(Rect::new(0, 0, available, 1))
+        .iter()
+        .map(|r| r.width)
+        .collect();
+
+    // Wrapped rows are taller, so count how many fit from the current offset
+    let row_heights: Vec<usize> = view
+        .visible
+        .iter()
+        .map(|&i| {
+            columns
+                .clone()
+                .zip(&column_widths)
+                .filter(|(c, _)| formats[*c].wrap)
+                .map(|(c, &width)| {
+                    let text = row_data[i].get(c).map_or("", |s| s.as_str());
+                    text.lines()
+                        .map(|line| wrap_line(line, (width as usize).max(1)).len())
+                        .sum::<usize>()
+                })
+                .max()
+                .unwrap_or(1)
+                .max(1)
+        })
+        .collect();
+
+    view.row_heights.clone_from(&row_heights);
+    let mut used = 0;
+    view.page = row_heights
+        .iter()
+        .skip(view.state.offset())
+        .take_while(|&&h| {
+            used += h;
+            used <= rows_height
+        })
+        .count()
+        .max(1);
+    view.clamp();
+
     // Create header row
     let header_style = if args.highlight_header {
-        text_style.add_modifier(ratatui::style::Modifier::BOLD)
+        text_style.add_modifier(Modifier::BOLD)
     } else {
         text_style
     };
 
-    let header_cells: Vec<_> = header_data.iter().map(|h| h.as_str()).collect();
-    let header = Row::new(header_cells).style(header_style);
+    // Mark the sort column with an arrow
+    let mut header_cells: Vec<String> = columns
+        .clone()
+        .map(|i| header_data.get(i).cloned().unwrap_or_default())
+        .collect();
+    if let Some(sort) = view.sort
+        && columns.contains(&sort.column)
+        && !header_data.is_empty()
+    {
+        let arrow = if sort.descending { " ▼" } else { " ▲" };
+        header_cells[sort.column - columns.start].push_str(arrow);
+    }
+    let header = Row::new(
+        header_cells
+            .iter()
+            .zip(&column_widths)
+            .zip(columns.clone())
+            .map(|((text, &width), i)| {
+                let format = ColumnFormat {
+                    wrap: false,
+                    ..formats[i].clone()
+                };
+                Cell::from(cell_text(text, width, &format))
+            }),
+    )
+    .style(header_style);
+
+    let cursor = view
+        .state
+        .selected()
+        .and_then(|c| view.visible.get(c))
+        .copied();
+    let cells = |row: usize| -> Vec<Cell<'static>> {
+        columns
+            .clone()
+            .zip(&column_widths)
+            .map(|(i, &width)| {
+                let style = display.cell_styles[row][i];
+                if let Some(ref editor) = view.editor
+                    && cursor == Some(row)
+                    && i == view.column
+                {
+                    return Cell::from(editor_line(editor, width, text_style)).style(style);
+                }
+                let text = row_data[row].get(i).map_or("", |c| c.as_str());
+                Cell::from(cell_text(text, width, &formats[i])).style(style)
+            })
+            .collect()
+    };
 
     // Create data rows
-    let rows: Vec<Row> = row_data
+    let stripe_bg = args.stripe_bg.as_deref().map(parse_color).transpose()?;
+    let rows: Vec<Row> = view
+        .visible
         .iter()
-        .map(|row| {
-            let cells: Vec<_> = row.iter().map(|c| c.as_str()).collect();
-            Row::new(cells).style(text_style)
+        .zip(&row_heights)
+        .enumerate()
+        .map(|(n, (&i, &height))| {
+            let mut row_style = text_style;
+            if n % 2 == 1
+                && let Some(stripe) = stripe_bg
+            {
+                row_style = row_style.bg(stripe);
+            }
+            if view.selected[i] {
+                row_style = row_style.add_modifier(Modifier::BOLD);
+            }
+            Row::new(cells(i)).height(height as u16).style(row_style)
         })
         .collect();
 
     // Create table
-    let mut table = RatatuiTable::new(rows, widths).header(header);
+    let mut table = RatatuiTable::new(rows, widths);
+    if !header_data.is_empty() {
+        table = table.header(header);
+    }
+
+    if view.editable {
+        // Highlight the cell under the cursor, or nothing while the editor draws its own cursor
+        view.state
+            .select_column(Some(view.column.saturating_sub(columns.start)));
+        let cell_style = if view.editor.is_some() {
+            Style::default()
+        } else {
+            args.style.highlight_style()?
+        };
+        table = table
+            .cell_highlight_style(cell_style)
+            .highlight_symbol("> ")
+            .highlight_spacing(HighlightSpacing::Always);
+    } else if view.selecting {
+        table = table
+            .row_highlight_style(args.style.highlight_style()?)
+            .highlight_symbol("> ")
+            .highlight_spacing(HighlightSpacing::Always);
+    }
 
     // Add block if title or border is specified
-    if !args.title.is_empty() || args.style.border != "none" {
+    if has_block {
         let border_type = args.style.border_type()?;
         let border_style = args.style.border_style()?;
-        let block = Block::default()
-            .title(args.title.as_str())
-            .borders(if args.style.border == "none" {
-                Borders::NONE
-            } else {
+
+        let mut title = args.title.clone();
+        if args.multi {
+            let count = view.selected.iter().filter(|s| **s).count();
+            title = format!("{} ({} selected)", title, count)
+                .trim_start()
+                .to_string();
+        }
+
+        // Show the scroll position in the bottom border
+        let offset = view.state.offset();
+        let shown = view.visible.len();
+        let mut footer = if shown == 0 {
+            "no rows".to_string()
+        } else {
+            format!(
+                "rows {}–{} of {}",
+                offset + 1,
+                (offset + view.page).min(shown),
+                shown
+            )
+        };
+        if shown < row_data.len() {
+            footer.push_str(&format!(" ({} total)", row_data.len()));
+        }
+        if view.max_col_offset > 0 {
+            footer.push_str(&format!(
+                ", cols {}–{} of {}",
+                columns.start + 1,
+                columns.end,
+                natural_widths.len()
+            ));
+        }
+
+        let mut block = Block::default()
+            .title(title)
+            .borders(if has_border {
                 Borders::ALL
+            } else {
+                Borders::NONE
             })
             .border_type(border_type)
             .border_style(border_style);
+        let scrollable = shown > view.page || shown < row_data.len() || view.max_col_offset > 0;
+        if has_border && scrollable && !view.printing {
+            block = block.title_bottom(Line::from(format!(" {} ", footer)).right_aligned());
+        }
         table = table.block(block);
     }
 
-    frame.render_widget(table, area);
+    StatefulWidget::render(table, area, buf, &mut view.state);
+
+    // Scrollbar alongside the rows when not every row fits
+    if view.visible.len() > view.page && !view.printing {
+        let mut scrollbar_state = ScrollbarState::new(view.max_offset() + 1)
+            .position(view.state.offset())
+            .viewport_content_length(view.page);
+        let top = border_size / 2 + header_height + title_height;
+        let scrollbar_area = Rect {
+            y: area.y + top,
+            height: area.height.saturating_sub(top + border_size / 2),
+            ..area
+        };
+        Scrollbar::new(ScrollbarOrientation::VerticalRight)
+            .begin_symbol(None)
+            .end_symbol(None)
+            .render(scrollbar_area, buf, &mut scrollbar_state);
+    }
+
     Ok(())
 }
 
+/// The value of the cell being edited with a block cursor, scrolled to keep the cursor visible
+fn editor_line(editor: &Input, width: u16, style: Style) -> Line<'static> {
+    let scroll = editor.visual_scroll((width as usize).saturating_sub(1));
+    let cursor_style = style.add_modifier(Modifier::REVERSED);
+
+    let mut spans = Vec::new();
+    let mut position = 0;
+    for (i, ch) in editor.value().chars().enumerate() {
+        position += ch.to_string().width();
+        if position <= scroll {
+            continue;
+        }
+        let ch_style = if i == editor.cursor() {
+            cursor_style
+        } else {
+            style
+        };
+        spans.push(Span::styled(ch.to_string(), ch_style));
+    }
+    if editor.cursor() >= editor.value().chars().count() {
+        spans.push(Span::styled(" ", cursor_style));
+    }
+    Line::from(spans)
+}
+
+/// Most lines of rows drawn into one buffer by `print_table`
+const PRINT_CHUNK_LINES: usize = 4096;
+
+/// Render every row of the table as lines of text for stdout
+fn print_table(
+    args: &TableArgs,
+    header: &[String],
+    display: &mut TableDisplay,
+    view: &mut TableView,
+) -> Result<Vec<String>> {
+    if args.plain {
+        return Ok(plain_lines(header, display, &view.visible));
+    }
+    if args.markdown {
+        return Ok(markdown_lines(header, display, &view.visible));
+    }
+
+    let has_border = args.style.border != "none";
+    let border_size = if has_border { 2 } else { 0 };
+    let num_cols = display.natural_widths.len() as u16;
+    let content_width = display
+        .natural_widths
+        .iter()
+        .map(|&w| w as usize)
+        .sum::<usize>()
+        + num_cols.saturating_sub(1) as usize
+        + border_size as usize;
+    let title_width = args.title.width() as u16 + border_size;
+    let mut width = content_width
+        .max(title_width as usize)
+        .clamp(1, u16::MAX as usize) as u16;
+
+    // Don't draw past the edge of the terminal. Columns are narrowed to fit
+    // rather than scrolled, so every column is printed.
+    if stdout().is_terminal()
+        && let Ok((columns, _)) = crossterm::terminal::size()
+        && width > columns
+    {
+        width = columns;
+        let gaps = num_cols.saturating_sub(1);
+        let available = columns.saturating_sub(border_size + gaps);
+        shrink_widths(&mut display.natural_widths, available);
+    }
+
+    // Lay out once to find out how tall the wrapped rows are
+    view.printing = true;
+    let mut buf = Buffer::empty(Rect::new(0, 0, width, 1));
+    render(buf.area, &mut buf, args, header, display, view)?;
+
+    let header_height = if header.is_empty() { 0 } else { 1 };
+    let title_height = if !args.title.is_empty() && !has_border {
+        1
+    } else {
+        0
+    };
+    // Lines above and below the rows
+    let top = border_size / 2 + title_height + header_height;
+    let bottom = border_size / 2;
+
+    // A buffer is at most u16::MAX lines tall, so long tables are drawn a few
+    // thousand lines at a time and stitched together without the borders in between
+    let visible = std::mem::take(&mut view.visible);
+    let heights = std::mem::take(&mut view.row_heights);
+    let mut lines = Vec::new();
+    let mut start = 0;
+    loop {
+        let mut end = start;
+        let mut height = 0;
+        while end < visible.len() && (end == start || height + heights[end] <= PRINT_CHUNK_LINES) {
+            height += heights[end];
+            end += 1;
+        }
+        // Keep an even number of rows in every chunk so stripes carry on
+        if end < visible.len() && (end - start) % 2 == 1 {
+            if end - start > 1 {
+                end -= 1;
+                height -= heights[end];
+            } else {
+                height += heights[end];
+                end += 1;
+            }
+        }
+
+        view.visible = visible[start..end].to_vec();
+        let height = (height + (top + bottom) as usize).min(u16::MAX as usize) as u16;
+        let mut buf = Buffer::empty(Rect::new(0, 0, width, height.max(1)));
+        render(buf.area, &mut buf, args, header, display, view)?;
+
+        let mut chunk = ansi::buffer_to_lines(&buf);
+        if end < visible.len() {
+            chunk.truncate(chunk.len().saturating_sub(bottom as usize));
+        }
+        if start > 0 {
+            chunk.drain(..(top as usize).min(chunk.len()));
+        }
+        lines.extend(chunk.into_iter().map(|line| line.trim_end().to_string()));
+
+        start = end;
+        if start >= visible.len() {
+            break;
+        }
+    }
+    view.visible = visible;
+
+    Ok(lines)
+}
+
+/// Narrow the widest columns one step at a time until they fit in `available`
+fn shrink_widths(widths: &mut [u16], available: u16) {
+    while widths.iter().map(|&w| w as usize).sum::<usize>() > available as usize {
+        match widths.iter_mut().max() {
+            Some(widest) if *widest > 1 => *widest -= 1,
+            _ => break,
+        }
+    }
+}
+
+/// Pad `text` to `width` columns according to `alignment`
+fn align_text(text: &str, width: usize, alignment: Alignment) -> String {
+    let space = width.saturating_sub(text.width());
+    let (before, after) = match alignment {
+        Alignment::Left => (0, space),
+        Alignment::Center => (space / 2, space - space / 2),
+        Alignment::Right => (space, 0),
+    };
+    format!("{}{}{}", " ".repeat(before), text, " ".repeat(after))
+}
+
+fn plain_lines(header: &[String], display: &TableDisplay, visible: &[usize]) -> Vec<String> {
+    let line = |row: &[String]| -> String {
+        display
+            .formats
+            .iter()
+            .zip(&display.natural_widths)
+            .enumerate()
+            .map(|(i, (format, &width))| {
+                let text = row.get(i).map_or(String::new(), |c| c.replace('\n', " "));
+                let text = match format.max_width {
+                    Some(_) => truncate(&text, width as usize),
+                    None => text,
+                };
+                align_text(&text, width as usize, format.alignment)
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
+            .trim_end()
+            .to_string()
+    };
+
+    let mut lines = Vec::new();
+    if !header.is_empty() {
+        lines.push(line(header));
+    }
+    lines.extend(visible.iter().map(|&i| line(&display.rows[i])));
+    lines
+}
+
+fn markdown_lines(header: &[String], display: &TableDisplay, visible: &[usize]) -> Vec<String> {
+    let escape = |cell: &str| cell.replace('|', "\\|").replace('\n', "<br>");
+    let line = |row: &[String]| -> String {
+        let cells: Vec<String> = (0..display.formats.len())
+            .map(|i| escape(row.get(i).map_or("", |c| c.as_str())))
+            .collect();
+        format!("| {} |", cells.join(" | "))
+    };
+
+    // Markdown tables always have a header row, even if it is empty
+    let separators: Vec<&str> = display
+        .formats
+        .iter()
+        .map(|format| match format.alignment {
+            Alignment::Left => "---",
+            Alignment::Center => ":---:",
+            Alignment::Right => "---:",
+        })
+        .collect();
+
+    let mut lines = vec![line(header), format!("| {} |", separators.join(" | "))];
+    lines.extend(visible.iter().map(|&i| line(&display.rows[i])));
+    lines
+}
+
 fn parse_widths(width_str: &str, num_cols: usize) -> Result<Vec<Constraint>> {
     let widths: std::result::Result<Vec<u16>, _> = width_str
         .split(',')
@@ -0,0 +1,432 @@
+use crate::{
+    error::{NibbleError, Result},
+    style::StyleConfig,
+    tui,
+};
+use clap::Args;
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEvent, KeyModifiers};
+use ratatui::{
+    Frame,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+};
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+#[derive(Args, Debug)]
+pub struct WriteArgs {
+    /// Placeholder text when the text area is empty
+    #[arg(short, long, default_value = "")]
+    pub placeholder: String,
+
+    /// Initial value for the text area
+    #[arg(short = 'v', long, default_value = "")]
+    pub value: String,
+
+    /// Prompt text to display in front of every line
+    #[arg(short = 'r', long, default_value = "")]
+    pub prompt: String,
+
+    /// Title of the text area block
+    #[arg(short, long, default_value = "")]
+    pub title: String,
+
+    /// Height of the text area in lines
+    #[arg(long, default_value = "6")]
+    pub height: u16,
+
+    /// Maximum number of lines
+    #[arg(long)]
+    pub max_lines: Option<usize>,
+
+    /// Character limit (max length, including newlines)
+    #[arg(short = 'm', long)]
+    pub char_limit: Option<usize>,
+
+    /// Show line numbers
+    #[arg(short = 'n', long)]
+    pub line_numbers: bool,
+
+    /// Show character count
+    #[arg(short = 'c', long)]
+    pub show_count: bool,
+
+    #[command(flatten)]
+    pub style: StyleConfig,
+}
+
+/// Multi-line text buffer with a cursor
+struct TextArea {
+    lines: Vec<Vec<char>>,
+    row: usize,
+    col: usize,
+    /// First visual (wrapped) row shown in the viewport
+    scroll: usize,
+}
+
+impl TextArea {
+    fn new(value: &str) -> Self {
+        let lines: Vec<Vec<char>> = value.split('\n').map(|l| l.chars().collect()).collect();
+        let row = lines.len() - 1;
+        let col = lines[row].len();
+
+        Self {
+            lines,
+            row,
+            col,
+            scroll: 0,
+        }
+    }
+
+    fn value(&self) -> String {
+        self.lines
+            .iter()
+            .map(|l| l.iter().collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn is_empty(&self) -> bool {
+        self.lines.len() == 1 && self.lines[0].is_empty()
+    }
+
+    fn char_count(&self) -> usize {
+        self.lines.iter().map(|l| l.len()).sum::<usize>() + self.lines.len() - 1
+    }
+
+    fn insert_char(&mut self, ch: char) {
+        self.lines[self.row].insert(self.col, ch);
+        self.col += 1;
+    }
+
+    fn insert_newline(&mut self) {
+        let rest = self.lines[self.row].split_off(self.col);
+        self.lines.insert(self.row + 1, rest);
+        self.row += 1;
+        self.col = 0;
+    }
+
+    fn backspace(&mut self) {
+        if self.col > 0 {
+            self.col -= 1;
+            self.lines[self.row].remove(self.col);
+        } else if self.row > 0 {
+            let line = self.lines.remove(self.row);
+            self.row -= 1;
+            self.col = self.lines[self.row].len();
+            self.lines[self.row].extend(line);
+        }
+    }
+
+    fn delete(&mut self) {
+        if self.col < self.lines[self.row].len() {
+            self.lines[self.row].remove(self.col);
+        } else if self.row + 1 < self.lines.len() {
+            let line = self.lines.remove(self.row + 1);
+            self.lines[self.row].extend(line);
+        }
+    }
+
+    fn move_left(&mut self) {
+        if self.col > 0 {
+            self.col -= 1;
+        } else if self.row > 0 {
+            self.row -= 1;
+            self.col = self.lines[self.row].len();
+        }
+    }
+
+    fn move_right(&mut self) {
+        if self.col < self.lines[self.row].len() {
+            self.col += 1;
+        } else if self.row + 1 < self.lines.len() {
+            self.row += 1;
+            self.col = 0;
+        }
+    }
+
+    fn move_up(&mut self) {
+        if self.row > 0 {
+            self.row -= 1;
+            self.col = self.col.min(self.lines[self.row].len());
+        }
+    }
+
+    fn move_down(&mut self) {
+        if self.row + 1 < self.lines.len() {
+            self.row += 1;
+            self.col = self.col.min(self.lines[self.row].len());
+        }
+    }
+}
+
+pub fn run(args: WriteArgs) -> anyhow::Result<()> {
+    // Validate args
+    if args.height == 0 {
+        return Err(
+            NibbleError::InvalidDimensions("Height must be greater than 0".to_string()).into(),
+        );
+    }
+
+    if args.max_lines == Some(0) {
+        return Err(
+            NibbleError::ConfigError("Max lines must be greater than 0".to_string()).into(),
+        );
+    }
+
+    let mut terminal = tui::init_inline(args.height)?;
+    let mut textarea = TextArea::new(&args.value);
+
+    let result = loop {
+        terminal
+            .draw(|frame| {
+                if let Err(e) = render(frame, &args, &mut textarea) {
+                    eprintln!("Render error: {}", e);
+                }
+            })
+            .map_err(|e| NibbleError::RenderError(e.to_string()))?;
+
+        if let Event::Key(key) = event::read()? {
+            match handle_key_event(key, &mut textarea, &args) {
+                WriteAction::Continue => {}
+                WriteAction::Submit => break Some(textarea.value()),
+                WriteAction::Cancel => break None,
+            }
+        }
+    };
+
+    // Clear and restore terminal FIRST
+    terminal.clear()?;
+    tui::restore()?;
+
+    // THEN print the output
+    if let Some(value) = result {
+        println!("{}", value);
+    }
+
+    Ok(())
+}
+
+enum WriteAction {
+    Continue,
+    Submit,
+    Cancel,
+}
+
+fn handle_key_event(key: KeyEvent, textarea: &mut TextArea, args: &WriteArgs) -> WriteAction {
+    let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+    let at_limit = args
+        .char_limit
+        .is_some_and(|max| textarea.char_count() >= max);
+
+    match key.code {
+        KeyCode::Char('d') | KeyCode::Char('s') if ctrl => return WriteAction::Submit,
+        KeyCode::Esc => return WriteAction::Cancel,
+        KeyCode::Char('c') if ctrl => return WriteAction::Cancel,
+
+        KeyCode::Enter => {
+            let line_limit = args
+                .max_lines
+                .is_some_and(|max| textarea.lines.len() >= max);
+            if !at_limit && !line_limit {
+                textarea.insert_newline();
+            }
+        }
+        KeyCode::Char(ch) if !ctrl && !at_limit => textarea.insert_char(ch),
+        KeyCode::Tab if !at_limit => textarea.insert_char('\t'),
+        KeyCode::Backspace => textarea.backspace(),
+        KeyCode::Delete => textarea.delete(),
+        KeyCode::Left => textarea.move_left(),
+        KeyCode::Right => textarea.move_right(),
+        KeyCode::Up => textarea.move_up(),
+        KeyCode::Down => textarea.move_down(),
+        KeyCode::Home => textarea.col = 0,
+        KeyCode::End => textarea.col = textarea.lines[textarea.row].len(),
+        _ => {}
+    }
+
+    WriteAction::Continue
+}
+
+/// A visual row: a slice of a logical line that fits the available width
+struct VisualRow {
+    line: usize,
+    start: usize,
+    end: usize,
+}
+
+/// Wrap every logical line to `width` columns
+fn wrap_lines(textarea: &TextArea, width: usize) -> Vec<VisualRow> {
+    let mut rows = Vec::new();
+
+    for (index, line) in textarea.lines.iter().enumerate() {
+        let mut start = 0;
+        let mut used = 0;
+
+        for (i, ch) in line.iter().enumerate() {
+            let ch_width = display_char(*ch).width().unwrap_or(0);
+            if used + ch_width > width && i > start {
+                rows.push(VisualRow {
+                    line: index,
+                    start,
+                    end: i,
+                });
+                start = i;
+                used = 0;
+            }
+            used += ch_width;
+        }
+
+        rows.push(VisualRow {
+            line: index,
+            start,
+            end: line.len(),
+        });
+
+        // Leave room for the block cursor when it sits after a full row
+        if index == textarea.row && textarea.col == line.len() && used >= width && !line.is_empty()
+        {
+            rows.push(VisualRow {
+                line: index,
+                start: line.len(),
+                end: line.len(),
+            });
+        }
+    }
+
+    rows
+}
+
+/// Tabs are displayed as a single space to keep the width math simple
+fn display_char(ch: char) -> char {
+    if ch == '\t' { ' ' } else { ch }
+}
+
+fn render(frame: &mut Frame, args: &WriteArgs, textarea: &mut TextArea) -> Result<()> {
+    let area = frame.area();
+    let text_style = args.style.text_style()?;
+
+    // Add block if title or border is specified
+    let inner = if !args.title.is_empty() || args.style.border != "none" {
+        let border_type = args.style.border_type()?;
+        let border_style = args.style.border_style()?;
+
+        let title = if args.show_count {
+            let count = match args.char_limit {
+                Some(max) => format!("({}/{})", textarea.char_count(), max),
+                None => format!("({})", textarea.char_count()),
+            };
+            format!("{} {}", args.title, count).trim_start().to_string()
+        } else {
+            args.title.clone()
+        };
+
+        let block = Block::default()
+            .title(title)
+            .borders(if args.style.border == "none" {
+                Borders::NONE
+            } else {
+                Borders::ALL
+            })
+            .border_type(border_type)
+            .border_style(border_style);
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+        inner
+    } else {
+        area
+    };
+
+    if inner.width == 0 || inner.height == 0 {
+        return Ok(());
+    }
+
+    let gutter_width = if args.line_numbers {
+        textarea.lines.len().to_string().len() + 1
+    } else {
+        0
+    };
+    let prompt_width = args.prompt.width();
+    let text_width = (inner.width as usize)
+        .saturating_sub(gutter_width + prompt_width)
+        .max(1);
+
+    let rows = wrap_lines(textarea, text_width);
+    let height = inner.height as usize;
+
+    // Keep the cursor row inside the viewport
+    let cursor_row = rows
+        .iter()
+        .rposition(|r| r.line == textarea.row && r.start <= textarea.col)
+        .unwrap_or(0);
+    if cursor_row < textarea.scroll {
+        textarea.scroll = cursor_row;
+    } else if cursor_row >= textarea.scroll + height {
+        textarea.scroll = cursor_row + 1 - height;
+    }
+
+    let gutter_style = Style::default().fg(Color::DarkGray);
+    let prompt_style = text_style.add_modifier(Modifier::BOLD);
+    let cursor_style = text_style.add_modifier(Modifier::REVERSED);
+
+    let lines: Vec<Line> = rows
+        .iter()
+        .enumerate()
+        .skip(textarea.scroll)
+        .take(height)
+        .map(|(visual_index, row)| {
+            let mut spans = Vec::new();
+            let first_segment = row.start == 0;
+
+            if args.line_numbers {
+                let number = if first_segment {
+                    (row.line + 1).to_string()
+                } else {
+                    String::new()
+                };
+                spans.push(Span::styled(
+                    format!("{:>width$} ", number, width = gutter_width - 1),
+                    gutter_style,
+                ));
+            }
+
+            if !args.prompt.is_empty() {
+                spans.push(Span::styled(args.prompt.as_str(), prompt_style));
+            }
+
+            // Show placeholder with cursor at start
+            if textarea.is_empty() && !args.placeholder.is_empty() {
+                spans.push(Span::styled(
+                    " ",
+                    Style::default()
+                        .fg(Color::DarkGray)
+                        .add_modifier(Modifier::REVERSED),
+                ));
+                spans.push(Span::styled(
+                    args.placeholder.as_str(),
+                    Style::default().fg(Color::DarkGray),
+                ));
+                return Line::from(spans);
+            }
+
+            let chars = &textarea.lines[row.line][row.start..row.end];
+            for (i, ch) in chars.iter().enumerate() {
+                let is_cursor = visual_index == cursor_row && row.start + i == textarea.col;
+                spans.push(Span::styled(
+                    display_char(*ch).to_string(),
+                    if is_cursor { cursor_style } else { text_style },
+                ));
+            }
+
+            // If cursor is at the end, show block cursor
+            if visual_index == cursor_row && textarea.col >= row.end {
+                spans.push(Span::styled(" ", cursor_style));
+            }
+
+            Line::from(spans)
+        })
+        .collect();
+
+    frame.render_widget(Paragraph::new(lines), inner);
+    Ok(())
+}
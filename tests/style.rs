@@ -0,0 +1,30 @@
+use std::process::Command;
+
+fn style(args: &[&str]) -> Vec<String> {
+    let output = Command::new(env!("CARGO_BIN_EXE_nibble"))
+        .arg("style")
+        .args(args)
+        .output()
+        .expect("failed to run nibble");
+    assert!(output.status.success());
+    String::from_utf8(output.stdout)
+        .unwrap()
+        .lines()
+        .map(str::to_string)
+        .collect()
+}
+
+#[test]
+fn borderless_block_prints_every_line() {
+    let lines = style(&["--border", "none", "a", "b"]);
+    assert_eq!(lines.len(), 2);
+    assert_eq!(lines[1].trim(), "b");
+}
+
+#[test]
+fn borderless_title_gets_its_own_row() {
+    let lines = style(&["--border", "none", "--title", "X", "a", "b"]);
+    assert_eq!(lines.len(), 3);
+    assert_eq!(lines[0].trim(), "X");
+    assert_eq!(lines[2].trim(), "b");
+}
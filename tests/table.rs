@@ -0,0 +1,52 @@
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+fn table(args: &[&str], input: &str) -> std::process::Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_nibble"))
+        .arg("table")
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("failed to run nibble");
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(input.as_bytes())
+        .unwrap();
+    child.wait_with_output().unwrap()
+}
+
+#[test]
+fn print_writes_every_row_of_a_long_table() {
+    let rows = 70_000;
+    let mut csv = String::from("id,name\n");
+    for i in 0..rows {
+        csv.push_str(&format!("{},row{}\n", i, i));
+    }
+
+    let output = table(&["--print"], &csv);
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let lines: Vec<&str> = stdout.lines().collect();
+
+    // Top border, header, every row and the bottom border
+    assert_eq!(lines.len(), rows + 3);
+    let cells = |line: &str| -> Vec<String> {
+        line.trim_matches('│')
+            .split_whitespace()
+            .map(str::to_string)
+            .collect()
+    };
+    assert_eq!(cells(lines[2]), ["0", "row0"]);
+    assert_eq!(cells(lines[rows + 1]), ["69999", "row69999"]);
+    assert!(!stdout.contains(" of "));
+}
+
+#[test]
+fn print_rejects_select() {
+    let output = table(&["--print", "--select"], "a,b\n1,2\n");
+    assert!(!output.status.success());
+}